@@ -0,0 +1,16 @@
+//! Day 1 of 2021: Sonar Sweep.
+//!
+//! Counts how often the depth measurements of a sonar sweep increase. Part 2 is not solved yet.
+//!
+//! ```
+//! use day_2021_01::{parse, solve_part_one};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 7.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
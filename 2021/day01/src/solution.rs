@@ -0,0 +1,32 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// The depth measurements, in order.
+pub type Parsed = Vec<u32>;
+
+/// Parses one depth measurement per line.
+pub fn parse(input: &str) -> Result<Parsed, ParseError> {
+    let p = Parser::new(input);
+    input.lines().map(|l| p.number(l)).collect()
+}
+
+/// How many measurements are deeper than the one before.
+pub fn solve_part_one(input: Parsed) -> Answer {
+    let mut count = 0;
+    let mut prev = u32::MAX;
+    for a in input {
+        if a > prev {
+            count += 1;
+        }
+        prev = a;
+    }
+
+    count.into()
+}
+
+/// Not solved yet.
+pub fn solve_part_two(_input: Parsed) -> Answer {
+    todo!("Part two is not yet implemented");
+}
@@ -0,0 +1,17 @@
+//! Day 3 of 2021: Binary Diagnostic.
+//!
+//! Finds the gamma and epsilon rates from the most and least common bits of a diagnostic report.
+//! Part 2 is not solved yet.
+//!
+//! ```
+//! use day_2021_03::{parse, solve_part_one};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 198.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,58 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// The diagnostic report, and how many bits the numbers in it have.
+pub type Parsed = (Vec<usize>, usize);
+
+/// Parses one binary number per line, which all have the same number of bits.
+pub fn parse(input: &str) -> Result<Parsed, ParseError> {
+    let p = Parser::new(input);
+    let bit_len = input.lines().next().map_or(0, |l| l.trim().len());
+    let numbers = input
+        .lines()
+        .map(|l| {
+            let l = l.trim();
+            if let Some(i) = l.find(|c| c != '0' && c != '1') {
+                Err(p.error(&l[i..], "`0` or `1`"))
+            } else if l.len() != bit_len {
+                Err(p.error_after(l, format!("{bit_len} bits")))
+            } else {
+                Ok(usize::from_str_radix(l, 2).expect("only contains bits"))
+            }
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+
+    Ok((numbers, bit_len))
+}
+
+/// The power consumption: the gamma rate, made of the most common bits, times the epsilon
+/// rate, made of the least common ones.
+pub fn solve_part_one((parsed, bit_len): Parsed) -> Answer {
+    let bit_mask = (1 << bit_len) - 1;
+    let mut bits = vec![0i32; bit_len];
+
+    for num in parsed.into_iter() {
+        for (bit, count) in bits.iter_mut().enumerate() {
+            let the_bit = 1 << bit;
+            let bit_val = if num & the_bit > 0 { 1 } else { -1 };
+            *count += bit_val;
+        }
+    }
+
+    let mut gamma_rate = 0;
+    for (index, &bit) in bits.iter().enumerate() {
+        let gamma_bit_val = if bit > 0 { 1 << index } else { 0 };
+        gamma_rate += gamma_bit_val;
+    }
+
+    let epsilon_rate = gamma_rate ^ bit_mask;
+
+    (gamma_rate * epsilon_rate).into()
+}
+
+/// Not solved yet.
+pub fn solve_part_two((_parsed, _bit_len): Parsed) -> Answer {
+    todo!("Part two is not yet implemented");
+}
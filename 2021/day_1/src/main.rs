@@ -1,7 +0,0 @@
-use day_1_pm::solve_part_1;
-
-solve_part_1!();
-
-fn main() {
-    println!("{}", answer);
-}
@@ -0,0 +1,17 @@
+//! Day 1 of 2022: Calorie Counting.
+//!
+//! Finds the elves carrying the most calories.
+//!
+//! ```
+//! use day_2022_01::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 24000.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 45000.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,71 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// Parses the calories of each item, with the elves separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Option<u32>>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .lines()
+        .map(|l| parse_line(&p, l))
+        .collect::<Result<Vec<Option<u32>>, ParseError>>()
+}
+
+/// The calories on a line, `None` for the blank line between elves.
+pub fn parse_line<'a>(p: &Parser<'a>, l: &'a str) -> Result<Option<u32>, ParseError> {
+    if l.is_empty() {
+        Ok(None)
+    } else {
+        p.number(l).map(Some)
+    }
+}
+
+/// The most calories any elf carries.
+pub fn solve_part_one(input: impl Iterator<Item = Option<u32>>) -> Answer {
+    let mut curr = 0;
+    let mut highest_elf = 0;
+    for calories in input {
+        match calories {
+            None => {
+                if curr > highest_elf {
+                    highest_elf = curr;
+                }
+                curr = 0;
+            }
+            Some(n) => {
+                curr += n;
+            }
+        }
+    }
+    if curr > highest_elf {
+        highest_elf = curr;
+    }
+
+    highest_elf.into()
+}
+
+/// The calories the three elves carrying the most carry together.
+pub fn solve_part_two(input: impl Iterator<Item = Option<u32>>) -> Answer {
+    // Only the three highest totals are kept, highest first
+    let mut top = [0; 3];
+    let mut curr = 0;
+    for calories in input.chain([None]) {
+        match calories {
+            None => {
+                if let Some(i) = top.iter().position(|&t| curr > t) {
+                    top[i..].rotate_right(1);
+                    top[i] = curr;
+                }
+                curr = 0;
+            }
+            Some(n) => {
+                curr += n;
+            }
+        }
+    }
+
+    let total: u32 = top.iter().sum();
+
+    total.into()
+}
@@ -0,0 +1,17 @@
+//! Day 2 of 2022: Rock Paper Scissors.
+//!
+//! Scores the rounds of a strategy guide, read as moves and then as outcomes.
+//!
+//! ```
+//! use day_2022_02::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 15.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 12.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
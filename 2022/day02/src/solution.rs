@@ -0,0 +1,62 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// Parses the rounds of the strategy guide, see [`parse_line`].
+pub fn parse(input: &str) -> Result<Vec<(u8, u8)>, ParseError> {
+    let p = Parser::new(input);
+    input.lines().map(|l| parse_line(&p, l)).collect()
+}
+
+/// A round, as the letter of the opponent's move (`A`, `B` or `C`) and the letter of the second
+/// column (`X`, `Y` or `Z`), both as ASCII.
+pub fn parse_line<'a>(p: &Parser<'a>, l: &'a str) -> Result<(u8, u8), ParseError> {
+    match l.as_bytes() {
+        [abc @ b'A'..=b'C', b' ', xyz @ b'X'..=b'Z'] => Ok((*abc, *xyz)),
+        [b'A'..=b'C', b' ', b'X'..=b'Z', ..] => Err(p.error(&l[3..], "end of line")),
+        [b'A'..=b'C', b' ', ..] => Err(p.error(&l[2..], "one of X, Y, Z")),
+        [b'A'..=b'C', ..] => Err(p.error(&l[1..], "` `")),
+        _ => Err(p.error(l, "one of A, B, C")),
+    }
+}
+
+/// The total score when the second column is the move to play.
+pub fn solve_part_one(input: impl Iterator<Item = (u8, u8)>) -> Answer {
+    let val: u64 = input
+        .map(|bs| match bs {
+            (0x41, 0x58) => 1 + 3, // A, X
+            (0x42, 0x58) => 1,     // B, X
+            (0x43, 0x58) => 1 + 6, // C, X
+            (0x41, 0x59) => 2 + 6, // A, Y
+            (0x42, 0x59) => 2 + 3, // B, Y
+            (0x43, 0x59) => 2,     // C, Y
+            (0x41, 0x5A) => 3,     // A, Z
+            (0x42, 0x5A) => 3 + 6, // B, Z
+            (0x43, 0x5A) => 3 + 3, // C, Z
+            (a, b) => panic!("Pattern ({a}, {b}) is not supported!"),
+        } as u64)
+        .sum();
+
+    val.into()
+}
+
+/// The total score when the second column is how the round should end: lose, draw or win.
+pub fn solve_part_two(input: impl Iterator<Item = (u8, u8)>) -> Answer {
+    let val: u64 = input
+        .map(|(a, b)| match (a, b) {
+            (0x41, 0x58) => 3,     // A, X
+            (0x42, 0x58) => 1,     // B, X
+            (0x43, 0x58) => 2,     // C, X
+            (0x41, 0x59) => 1 + 3, // A, Y
+            (0x42, 0x59) => 2 + 3, // B, Y
+            (0x43, 0x59) => 3 + 3, // C, Y
+            (0x41, 0x5A) => 2 + 6, // A, Z
+            (0x42, 0x5A) => 3 + 6, // B, Z
+            (0x43, 0x5A) => 1 + 6, // C, Z
+            (a, b) => panic!("Pattern ({a}, {b}) is not supported!"),
+        } as u64)
+        .sum();
+
+    val.into()
+}
@@ -0,0 +1,18 @@
+//! Day 3 of 2022: Rucksack Reorganization.
+//!
+//! Finds the item in both compartments of each rucksack, and the badge each group of three elves
+//! shares.
+//!
+//! ```
+//! use day_2022_03::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 157.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 70.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,115 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// Parses the items of each rucksack, one letter per item.
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .lines()
+        .map(|l| {
+            if let Some(i) = l.find(|c: char| !c.is_ascii_alphabetic()) {
+                Err(p.error(&l[i..], "a letter"))
+            } else if l.len() % 2 != 0 {
+                Err(p.error_after(l, "an even number of items"))
+            } else {
+                Ok(l)
+            }
+        })
+        .collect()
+}
+
+/// The kinds of items, as a bit per priority.
+fn items(items: &str) -> u64 {
+    items.bytes().fold(0, |set, item| set | 1 << priority(item))
+}
+
+fn priority(item: u8) -> u32 {
+    match item {
+        b'a'..=b'z' => (item - b'a') as u32 + 1,
+        _ => (item - b'A') as u32 + 27,
+    }
+}
+
+/// The priority of the only item in all of the sets.
+fn shared(sets: impl Iterator<Item = u64>) -> u32 {
+    let shared = sets.fold(u64::MAX, |shared, set| shared & set);
+    assert!(shared.count_ones() == 1, "Expected a single shared item");
+    shared.trailing_zeros()
+}
+
+/// The sum of the priorities of the item in both compartments of each rucksack.
+pub fn solve_part_one<'a>(input: impl Iterator<Item = &'a str>) -> Answer {
+    let sum: u32 = input
+        .map(|l| {
+            let (first, second) = l.split_at(l.len() / 2);
+            shared([items(first), items(second)].into_iter())
+        })
+        .sum();
+
+    sum.into()
+}
+
+/// The sum of the priorities of the badge each group of three rucksacks shares.
+pub fn solve_part_two<'a>(input: impl Iterator<Item = &'a str>) -> Answer {
+    let input: Vec<&str> = input.collect();
+    let sum: u32 = input
+        .chunks(3)
+        .map(|group| shared(group.iter().map(|l| items(l))))
+        .sum();
+
+    sum.into()
+}
+
+/// Part 1 by comparing every pair of items, for checking [`solve_part_one`] against.
+pub fn reference_part_one<'a>(input: impl Iterator<Item = &'a str>) -> Answer {
+    let sum: u32 = input
+        .map(|l| l.split_at(l.len() / 2))
+        .map(|(first, second)| {
+            for char_1 in first.as_bytes().iter() {
+                for char_2 in second.as_bytes().iter() {
+                    if char_1 == char_2 {
+                        return char_1;
+                    }
+                }
+            }
+            panic!("Failed to find matching chars for strings {first} and {second}")
+        })
+        .map(|char| match *char {
+            c if c < 97 => c as u32 - 65 + 27,
+            c => c as u32 - 97 + 1,
+        })
+        .sum();
+
+    sum.into()
+}
+
+/// Part 2 by comparing every triple of items, for checking [`solve_part_two`] against.
+pub fn reference_part_two<'a>(input: impl Iterator<Item = &'a str>) -> Answer {
+    let input: Vec<&str> = input.collect();
+    let mut sum = 0;
+    for group_base_index in (0..input.len()).step_by(3) {
+        let first = input[group_base_index];
+        let second = input[group_base_index + 1];
+        let third = input[group_base_index + 2];
+
+        let mut group_char = 0;
+        for first_char in first.as_bytes().iter() {
+            for second_char in second.as_bytes().iter() {
+                for third_char in third.as_bytes().iter() {
+                    if first_char == second_char && second_char == third_char {
+                        group_char = *first_char;
+                    }
+                }
+            }
+        }
+
+        sum += match group_char {
+            c if c < 97 => c as u32 - 65 + 27,
+            c => c as u32 - 97 + 1,
+        }
+    }
+
+    sum.into()
+}
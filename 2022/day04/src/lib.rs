@@ -0,0 +1,18 @@
+//! Day 4 of 2022: Camp Cleanup.
+//!
+//! Counts the pairs of section assignments where one contains the other, and the ones that overlap
+//! at all.
+//!
+//! ```
+//! use day_2022_04::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 2.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 4.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
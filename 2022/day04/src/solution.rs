@@ -0,0 +1,55 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// The first and last section assigned to an elf.
+pub type Sections = (u32, u32);
+
+/// Parses the section assignments of each pair of elves, see [`parse_line`].
+pub fn parse(input: &str) -> Result<Vec<(Sections, Sections)>, ParseError> {
+    let p = Parser::new(input);
+    input.lines().map(|l| parse_line(&p, l)).collect()
+}
+
+/// The sections of the two elves of a pair, as the first and last section of each.
+pub fn parse_line<'a>(p: &Parser<'a>, l: &'a str) -> Result<(Sections, Sections), ParseError> {
+    let (elf_one, elf_two) = p.split_once(l, ",")?;
+    let (elf_one_low, elf_one_high) = p.split_once(elf_one, "-")?;
+    let (elf_two_low, elf_two_high) = p.split_once(elf_two, "-")?;
+
+    Ok((
+        (p.number(elf_one_low)?, p.number(elf_one_high)?),
+        (p.number(elf_two_low)?, p.number(elf_two_high)?),
+    ))
+}
+
+/// How many pairs have one assignment containing the other.
+pub fn solve_part_one(input: impl Iterator<Item = (Sections, Sections)>) -> Answer {
+    let sum: u64 = input
+        .map(|((l1, h1), (l2, h2))| {
+            if (l1 <= l2 && h1 >= h2) || (l2 <= l1 && h2 >= h1) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    sum.into()
+}
+
+/// How many pairs have overlapping assignments.
+pub fn solve_part_two(input: impl Iterator<Item = (Sections, Sections)>) -> Answer {
+    let sum: u64 = input
+        .map(|((l1, h1), (l2, h2))| {
+            if (l1 <= l2 && h1 >= l2) || (l2 <= l1 && h2 >= l1) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    sum.into()
+}
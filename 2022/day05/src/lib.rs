@@ -0,0 +1,20 @@
+//! Day 5 of 2022: Supply Stacks.
+//!
+//! Moves crates between stacks, one at a time with the CrateMover 9000 and several at once with the
+//! CrateMover 9001.
+//!
+//! ```
+//! use day_2022_05::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! let (stacks, moves) = parse(input).unwrap();
+//! assert_eq!(solve_part_one((stacks, moves.into_iter())), "CMZ".into());
+//! let (stacks, moves) = parse(input).unwrap();
+//! assert_eq!(solve_part_two((stacks, moves.into_iter())), "MCD".into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use aoc::{
+    parse::{ParseError, Parser},
+    visual::{self, Cell, Color, Frame},
+    Answer,
+};
+use aoc_utils::Point;
+
+/// The crates of each stack from the bottom up, by stack number.
+pub type Stacks = HashMap<u8, Vec<char>>;
+
+/// A move, as how many crates to move from which stack to which.
+pub type Move = (u32, u8, u8);
+
+/// Parses the drawing of the stacks and the moves below it.
+pub fn parse(input: &str) -> Result<(Stacks, Vec<Move>), ParseError> {
+    let p = Parser::new(input);
+    // Without any moves there is no blank line after the drawing, as it is trimmed off the end
+    let (graph, instructions) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    // The last line numbers the stacks, which may start out empty
+    let mut rows: Vec<&str> = graph.lines().collect();
+    let numbers = rows
+        .pop()
+        .ok_or_else(|| p.error(graph, "the stacks of crates"))?;
+    let mut map: Stacks = HashMap::new();
+    for (index, number) in numbers.split_whitespace().enumerate() {
+        let stack: u8 = p.number(number)?;
+        if usize::from(stack) != index + 1 {
+            return Err(p.error(number, format!("stack {}", index + 1)));
+        }
+        map.insert(stack, Vec::new());
+    }
+
+    let stacks = map.len();
+    for l in rows.into_iter().rev() {
+        // A crate is drawn as `[A]`, with a space between stacks
+        for (index, (at, c)) in l.char_indices().skip(1).step_by(4).enumerate() {
+            if c == ' ' {
+                continue;
+            }
+
+            let stack = u8::try_from(index + 1)
+                .ok()
+                .and_then(|index| map.get_mut(&index))
+                .ok_or_else(|| p.error(&l[at..], format!("at most {stacks} stacks")))?;
+            stack.push(c);
+        }
+    }
+
+    let instructions = instructions
+        .lines()
+        .map(|l| {
+            let (count, rest) = p.split_once(p.strip_prefix(l, "move ")?, " from ")?;
+            let (from_str, to_str) = p.split_once(rest, " to ")?;
+            let (from, to) = (p.number(from_str)?, p.number(to_str)?);
+            for (stack, at) in [(from, from_str), (to, to_str)] {
+                if !map.contains_key(&stack) {
+                    return Err(p.error(at, format!("a stack from 1 to {}", map.len())));
+                }
+            }
+            Ok((p.number(count)?, from, to))
+        })
+        .collect::<Result<Vec<Move>, ParseError>>()?;
+
+    Ok((map, instructions))
+}
+
+/// The top crates after the moves, when crates are moved one at a time.
+pub fn solve_part_one<'a>(input: (Stacks, impl Iterator<Item = Move> + 'a)) -> Answer {
+    let (mut map, instructions) = input;
+    visual::record(|| stacks_frame(&map, None));
+    for (count, from, to) in instructions {
+        let mut tmp_stack = Vec::new();
+        {
+            let from_stack = map.get_mut(&from).unwrap();
+            for _ in 0..count {
+                tmp_stack.push(from_stack.pop().unwrap());
+            }
+        }
+        tmp_stack.reverse();
+        {
+            let to_stack = map.get_mut(&to).unwrap();
+            for _ in 0..count {
+                to_stack.push(tmp_stack.pop().unwrap());
+            }
+        }
+        visual::record(|| stacks_frame(&map, Some((to, count))));
+    }
+
+    let mut solution = String::new();
+    for stack_index in 1..(map.len() + 1) {
+        if let Some(top) = map[&(stack_index as u8)].last() {
+            solution.push(*top);
+        }
+    }
+
+    solution.into()
+}
+
+/// The top crates after the moves, when the crates of a move are moved at once.
+pub fn solve_part_two<'a>(input: (Stacks, impl Iterator<Item = Move> + 'a)) -> Answer {
+    let (mut map, instructions) = input;
+    visual::record(|| stacks_frame(&map, None));
+    for (count, from, to) in instructions {
+        let mut tmp_stack = Vec::new();
+        {
+            let from_stack = map.get_mut(&from).unwrap();
+            for _ in 0..count {
+                tmp_stack.push(from_stack.pop().unwrap());
+            }
+        }
+        {
+            let to_stack = map.get_mut(&to).unwrap();
+            for _ in 0..count {
+                to_stack.push(tmp_stack.pop().unwrap());
+            }
+        }
+        visual::record(|| stacks_frame(&map, Some((to, count))));
+    }
+
+    let mut solution = String::new();
+    for stack_index in 1..(map.len() + 1) {
+        if let Some(top) = map[&(stack_index as u8)].last() {
+            solution.push(*top);
+        }
+    }
+
+    solution.into()
+}
+
+/// The stacks drawn the way the input does, with the crates that were just moved highlighted.
+fn stacks_frame(map: &Stacks, moved: Option<(u8, u32)>) -> Frame {
+    let mut cells = Vec::new();
+    for (&key, stack) in map.iter() {
+        let x = (key as i64 - 1) * 4;
+        cells.push((
+            Point::new(x + 1, 0),
+            Cell::new((b'0' + key % 10) as char, Color::GRAY),
+        ));
+
+        for (height, &c) in stack.iter().enumerate() {
+            let color = match moved {
+                Some((to, count)) if to == key && height + count as usize >= stack.len() => {
+                    Color::YELLOW
+                }
+                _ => Color::SAND,
+            };
+            let y = -(height as i64) - 1;
+            for (dx, glyph) in ['[', c, ']'].into_iter().enumerate() {
+                cells.push((Point::new(x + dx as i64, y), Cell::new(glyph, color)));
+            }
+        }
+    }
+
+    Frame::from_points(cells, Cell::BLANK)
+}
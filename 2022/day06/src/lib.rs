@@ -0,0 +1,18 @@
+//! Day 6 of 2022: Tuning Trouble.
+//!
+//! Finds the first packet and message markers in a datastream, where a window of characters are all
+//! different.
+//!
+//! ```
+//! use day_2022_06::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 7.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap()), 19.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,56 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// Parses the datastream of lowercase letters.
+pub fn parse(input: &str) -> Result<&[u8], ParseError> {
+    let input = input.trim_end();
+    match input.find(|c: char| !c.is_ascii_lowercase()) {
+        Some(i) => Err(Parser::new(input).error(&input[i..], "a lowercase letter")),
+        None => Ok(input.as_bytes()),
+    }
+}
+
+/// How many characters are read when the last `window_size` of them first are all different.
+#[inline(always)]
+pub fn find_packets(input: &[u8], window_size: usize) -> u32 {
+    'outer: for (index, arr) in input.windows(window_size).enumerate() {
+        for (i1, a) in arr.iter().enumerate() {
+            for (i2, b) in arr.iter().enumerate() {
+                if i1 != i2 && a == b {
+                    continue 'outer;
+                }
+            }
+        }
+        return (index + window_size) as u32;
+    }
+    panic!("Failed to find answer");
+}
+
+/// Like [`find_packets`], but takes the datastream a character at a time, so that it does not have
+/// to be in memory. Keeps where each letter was last seen instead of looking back at the window.
+pub fn find_packets_streaming(input: impl Iterator<Item = u8>, window_size: usize) -> u32 {
+    let mut last_seen = [0; 26];
+    // The window of distinct letters ending at the current one starts here, counting from 1
+    let mut start = 1;
+    for (i, c) in (1..).zip(input) {
+        let seen = &mut last_seen[(c - b'a') as usize];
+        start = start.max(*seen + 1);
+        *seen = i;
+        if i + 1 - start == window_size {
+            return i as u32;
+        }
+    }
+    panic!("Failed to find answer");
+}
+
+/// Where the start-of-packet marker of 4 different characters ends.
+pub fn solve_part_one(input: &[u8]) -> Answer {
+    find_packets(input, 4).into()
+}
+
+/// Where the start-of-message marker of 14 different characters ends.
+pub fn solve_part_two(input: &[u8]) -> Answer {
+    find_packets(input, 14).into()
+}
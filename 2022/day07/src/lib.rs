@@ -0,0 +1,18 @@
+//! Day 7 of 2022: No Space Left On Device.
+//!
+//! Works out the sizes of the directories from a terminal session, and which directory to delete to
+//! free up enough space.
+//!
+//! ```
+//! use day_2022_07::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 95437.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 24933642.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
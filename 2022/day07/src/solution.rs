@@ -1,41 +1,62 @@
 use std::collections::HashMap;
 
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// The file system, as far as the terminal session shows it.
 #[derive(Debug, Clone)]
 pub enum File {
+    /// A directory and what is in it, by name.
     Dir(HashMap<String, File>),
+    /// A file and its size.
     File(u32),
 }
 
+/// A line of the terminal session.
 #[derive(Debug, Clone)]
 pub enum Input {
+    /// `$ cd`, to a directory, `..` or `/`.
     Cd(String),
+    /// `$ ls`, which lists the current directory on the lines after it.
     Ls,
+    /// A directory listed by `ls`.
     DirPrint(String),
+    /// A file listed by `ls`, with its size.
     FilePrint(u32, String),
 }
 
-pub fn parse<'a>(input: &'a str) -> impl Iterator<Item = Input> + 'a {
-    input.lines().map(|l| {
-        if let Some(command) = l.strip_prefix("$ ") {
-            if let Some(dir) = command.strip_prefix("cd ") {
-                Input::Cd(dir.to_string())
-            } else {
-                Input::Ls
-            }
-        } else {
-            if let Some(dir) = l.strip_prefix("dir ") {
-                Input::DirPrint(dir.to_string())
+/// Parses the lines of the terminal session.
+pub fn parse(input: &str) -> Result<Vec<Input>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .lines()
+        .map(|l| {
+            Ok(if let Some(command) = l.strip_prefix("$ ") {
+                if let Some(dir) = command.strip_prefix("cd ") {
+                    Input::Cd(dir.to_string())
+                } else if command == "ls" {
+                    Input::Ls
+                } else {
+                    return Err(p.error(command, "`cd` or `ls`"));
+                }
             } else {
-                let (size, name) = l.split_once(" ").unwrap();
-                Input::FilePrint(size.parse().unwrap(), name.to_string())
-            }
-        }
-    })
+                if let Some(dir) = l.strip_prefix("dir ") {
+                    Input::DirPrint(dir.to_string())
+                } else {
+                    let (size, name) = p.split_once(l, " ")?;
+                    Input::FilePrint(p.number(size)?, name.to_string())
+                }
+            })
+        })
+        .collect()
 }
 
+/// The contents of the directory at `path_stack`, from the root.
 #[inline(always)]
-pub fn get_parent_map<'a>(
-    path_stack: &Vec<String>,
+fn get_parent_map<'a>(
+    path_stack: &[String],
     root_file: &'a mut File,
 ) -> &'a mut HashMap<String, File> {
     let mut curr_file = root_file;
@@ -60,7 +81,7 @@ fn find_dir_sizes(
     match file {
         File::Dir(map) => {
             let size = map
-                .into_iter()
+                .iter_mut()
                 .map(|(dir_name, dir)| {
                     find_dir_sizes(dir, dir_size_map, format!("{curr_file_path}/{dir_name}"))
                 })
@@ -68,7 +89,7 @@ fn find_dir_sizes(
             dir_size_map.insert(curr_file_path, size);
             size
         }
-        File::File(size) => size.clone(),
+        File::File(size) => *size,
     }
 }
 
@@ -76,7 +97,7 @@ fn find_root_sizes(root_file: &mut File) -> HashMap<String, u32> {
     let mut dir_size_map: HashMap<String, u32> = HashMap::new();
     match root_file {
         File::Dir(root_map) => {
-            for (_, file) in root_map {
+            for file in root_map.values_mut() {
                 find_dir_sizes(file, &mut dir_size_map, String::new());
             }
         }
@@ -85,7 +106,7 @@ fn find_root_sizes(root_file: &mut File) -> HashMap<String, u32> {
     dir_size_map
 }
 
-fn create_root_file<'a>(input: impl Iterator<Item = Input>) -> File {
+fn create_root_file(input: impl Iterator<Item = Input>) -> File {
     let mut root_file = File::Dir(HashMap::new());
 
     let mut path_stack: Vec<String> = vec![];
@@ -134,30 +155,28 @@ fn create_root_file<'a>(input: impl Iterator<Item = Input>) -> File {
     root_file
 }
 
-pub fn solve_part_one<'a>(input: impl Iterator<Item = Input>) -> String {
+/// The sum of the sizes of the directories of at most 100000.
+pub fn solve_part_one(input: impl Iterator<Item = Input>) -> Answer {
     let mut root_file = create_root_file(input);
     let dir_size_map = find_root_sizes(&mut root_file);
 
-    let solution: u32 = dir_size_map
-        .into_iter()
-        .map(|(_, v)| v)
-        .filter(|&v| v <= (100000 as u32))
-        .sum();
+    let solution: u32 = dir_size_map.into_values().filter(|&v| v <= 100000).sum();
 
-    format!("{solution}")
+    solution.into()
 }
 
 const TOTAL_SPACE: u32 = 70000000;
 const REQUIRED_DISK_SPACE: u32 = 30000000;
 
-pub fn solve_part_two<'a>(input: impl Iterator<Item = Input>) -> String {
+/// The size of the smallest directory that frees up enough space for the update when deleted.
+pub fn solve_part_two(input: impl Iterator<Item = Input>) -> Answer {
     let mut root_file = create_root_file(input);
 
     let dir_size_map = find_root_sizes(&mut root_file);
 
     // println!("DIR SIZE MAP {dir_size_map:#?}");
 
-    let total_used_space = dir_size_map.get("").unwrap().clone();
+    let total_used_space = *dir_size_map.get("").unwrap();
     // println!("total_used_space {total_used_space}");
     // println!("total_space      {TOTAL_SPACE}");
     let free_space = TOTAL_SPACE - total_used_space;
@@ -172,5 +191,5 @@ pub fn solve_part_two<'a>(input: impl Iterator<Item = Input>) -> String {
         }
     }
 
-    format!("{closest_val}")
+    closest_val.into()
 }
@@ -0,0 +1,17 @@
+//! Day 8 of 2022: Treetop Tree House.
+//!
+//! Counts the trees visible from outside the grid and finds the best scenic score.
+//!
+//! ```
+//! use day_2022_08::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 21.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap()), 8.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
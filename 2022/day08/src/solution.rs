@@ -0,0 +1,143 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+use aoc_utils::{Grid, Point};
+
+/// Parses the heights of the trees.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    let p = Parser::new(input);
+    p.grid("trees", |c, _| match c.as_bytes()[0] {
+        b @ b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err(p.error(c, "a digit")),
+    })
+}
+
+/// How many trees are visible from outside the grid.
+pub fn solve_part_one(input: Grid<u8>) -> Answer {
+    let width = input.width();
+    let mut visible_map = Grid::new(width, input.height(), false);
+
+    // Left to right
+    for (y, row) in input.rows().enumerate() {
+        let mut highest = -1;
+        for (x, tree) in row.iter().enumerate() {
+            if *tree as i32 > highest {
+                visible_map[Point::new(x, y)] = true;
+                highest = *tree as i32;
+            }
+        }
+    }
+
+    // Right to left
+    for (y, row) in input.rows().enumerate() {
+        let mut highest = -1;
+        for (x, tree) in row.iter().enumerate().rev() {
+            if *tree as i32 > highest {
+                visible_map[Point::new(x, y)] = true;
+                highest = *tree as i32;
+            }
+        }
+    }
+
+    let mut highest_row = vec![-1; width];
+    // Up to down
+    for (y, row) in input.rows().enumerate() {
+        for (x, tree) in row.iter().enumerate() {
+            let highest = highest_row[x];
+            if *tree as i32 > highest {
+                visible_map[Point::new(x, y)] = true;
+                highest_row[x] = *tree as i32;
+            }
+        }
+    }
+
+    let mut highest_row = vec![-1; width];
+    // Down to up
+    for (y, row) in input.rows().enumerate().rev() {
+        for (x, tree) in row.iter().enumerate() {
+            let highest = highest_row[x];
+            if *tree as i32 > highest {
+                visible_map[Point::new(x, y)] = true;
+                highest_row[x] = *tree as i32;
+            }
+        }
+    }
+
+    // println!("{visible_map:#?}");
+
+    let count = visible_map.iter().filter(|(_, &visible)| visible).count();
+
+    count.into()
+}
+
+fn find_visible_up(map: &Grid<u8>, x: usize, y: usize) -> u32 {
+    let tree = map[Point::new(x, y)];
+    let mut sum = 0;
+    for val in (0..y).rev() {
+        sum += 1;
+        if map[Point::new(x, val)] >= tree {
+            return sum;
+        }
+    }
+
+    sum
+}
+
+fn find_visible_down(map: &Grid<u8>, x: usize, y: usize) -> u32 {
+    let tree = map[Point::new(x, y)];
+    let mut sum = 0;
+    for val in (y..map.height()).skip(1) {
+        sum += 1;
+        if map[Point::new(x, val)] >= tree {
+            return sum;
+        }
+    }
+
+    sum
+}
+
+fn find_visible_right(map: &Grid<u8>, x: usize, y: usize) -> u32 {
+    let tree = map[Point::new(x, y)];
+    let mut sum = 0;
+    for val in (x..map.width()).skip(1) {
+        sum += 1;
+        if map[Point::new(val, y)] >= tree {
+            return sum;
+        }
+    }
+
+    sum
+}
+
+fn find_visible_left(map: &Grid<u8>, x: usize, y: usize) -> u32 {
+    let tree = map[Point::new(x, y)];
+    let mut sum = 0;
+    for val in (0..x).rev() {
+        sum += 1;
+        if map[Point::new(val, y)] >= tree {
+            return sum;
+        }
+    }
+
+    sum
+}
+
+/// The highest scenic score of any tree.
+pub fn solve_part_two(input: Grid<u8>) -> Answer {
+    let mut highest_scenic = 0;
+    for Point { x, y } in input.points() {
+        let visible_up = find_visible_up(&input, x, y);
+        let visible_down = find_visible_down(&input, x, y);
+        let visible_right = find_visible_right(&input, x, y);
+        let visible_left = find_visible_left(&input, x, y);
+        // println!("({x},{y}) :: {visible_up} {visible_down} {visible_right} {visible_left}");
+
+        let scenic = visible_up * visible_down * visible_right * visible_left;
+        if scenic > highest_scenic {
+            highest_scenic = scenic;
+        }
+    }
+
+    highest_scenic.into()
+}
@@ -0,0 +1,17 @@
+//! Day 9 of 2022: Rope Bridge.
+//!
+//! Counts the positions the tail of a rope visits, for a rope of two knots and one of ten.
+//!
+//! ```
+//! use day_2022_09::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 13.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 1.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
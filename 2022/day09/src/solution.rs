@@ -1,49 +1,52 @@
 use std::{collections::HashSet, fmt::Display};
 
+use aoc::{
+    parse::{ParseError, Parser},
+    visual::{self, Cell, Color, Frame},
+    Answer,
+};
+use aoc_utils::Point;
+
+/// A direction the head of the rope moves in.
 #[derive(Debug, Clone)]
 pub enum Dir {
+    /// Right.
     R,
+    /// Left.
     L,
+    /// Up.
     U,
+    /// Down.
     D,
 }
 
 impl Dir {
-    fn get_delta(&self) -> (i32, i32) {
+    fn get_delta(&self) -> Point<i32> {
         match self {
-            Dir::R => (1, 0),
-            Dir::L => (-1, 0),
-            Dir::U => (0, 1),
-            Dir::D => (0, -1),
+            Dir::R => Point::new(1, 0),
+            Dir::L => Point::new(-1, 0),
+            Dir::U => Point::new(0, 1),
+            Dir::D => Point::new(0, -1),
         }
     }
 }
 
+/// A move of the head of the rope.
 #[derive(Debug, Clone)]
 pub struct Instruction {
-    dir: Dir,
-    count: u8,
-}
-
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-struct Position {
-    x: i32,
-    y: i32,
+    /// Which way the head moves.
+    pub dir: Dir,
+    /// How many steps it moves.
+    pub count: u8,
 }
 
-impl Position {
-    fn dist(&self, other: &Position) -> f32 {
-        let dist_x = (self.x - other.x).abs();
-        let dist_y = (self.y - other.y).abs();
+type Position = Point<i32>;
 
-        return ((dist_x.pow(2) + dist_y.pow(2)) as f32).sqrt();
-    }
-}
+fn dist(a: &Position, b: &Position) -> f32 {
+    let dist_x = (a.x - b.x).abs();
+    let dist_y = (a.y - b.y).abs();
 
-impl Display for Position {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
+    ((dist_x.pow(2) + dist_y.pow(2)) as f32).sqrt()
 }
 
 #[derive(Debug, Clone)]
@@ -79,24 +82,30 @@ impl Display for HeadPos {
     }
 }
 
-pub fn parse<'a>(input: &'a str) -> impl Iterator<Item = Instruction> + 'a {
-    input.lines().map(|l| {
-        let (dir, count) = l.split_once(" ").unwrap();
-        let num = count.parse::<u8>().unwrap();
-        Instruction {
-            dir: match dir {
-                "R" => Dir::R,
-                "L" => Dir::L,
-                "U" => Dir::U,
-                "D" => Dir::D,
-                a => panic!("Invalid direction {a}"),
-            },
-            count: num,
-        }
-    })
+/// Parses the moves of the head, one per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .lines()
+        .map(|l| {
+            let (dir, count) = p.split_once(l, " ")?;
+            let num = p.number::<u8>(count)?;
+            Ok(Instruction {
+                dir: match dir {
+                    "R" => Dir::R,
+                    "L" => Dir::L,
+                    "U" => Dir::U,
+                    "D" => Dir::D,
+                    a => return Err(p.error(a, "one of R, L, U, D")),
+                },
+                count: num,
+            })
+        })
+        .collect()
 }
 
-pub fn solve_part_one<'a>(input: impl Iterator<Item = Instruction>) -> String {
+/// How many positions the tail of a rope of two knots visits.
+pub fn solve_part_one(input: impl Iterator<Item = Instruction>) -> Answer {
     let mut head_pos = HeadPos::Same;
     let mut tail_pos = Position { x: 0, y: 0 };
     let mut visited: HashSet<Position> = HashSet::new();
@@ -119,43 +128,53 @@ pub fn solve_part_one<'a>(input: impl Iterator<Item = Instruction>) -> String {
     visited.insert(tail_pos);
 
     // TODO: Off-by-one?
-    format!("{}", visited.len())
+    visited.len().into()
 }
 
-pub fn solve_part_two<'a>(input: impl Iterator<Item = Instruction>) -> String {
+/// How many positions the tail of a rope of ten knots visits.
+pub fn solve_part_two(input: impl Iterator<Item = Instruction>) -> Answer {
     let mut rope = vec![Position { x: 0, y: 0 }; 9];
     let mut visited: HashSet<Position> = HashSet::new();
 
     let mut head_pos = Position { x: 0, y: 0 };
     for ins in input {
-        let (dx, dy) = ins.dir.get_delta();
+        let delta = ins.dir.get_delta();
 
         for _ in 0..ins.count {
-            head_pos = Position {
-                x: head_pos.x + dx,
-                y: head_pos.y + dy,
-            };
+            head_pos += delta;
 
-            let mut prev_pos = head_pos.clone();
+            let mut prev_pos = head_pos;
             for knot_index in 0..rope.len() {
                 prev_pos = move_knot(&prev_pos, rope.get(knot_index).unwrap());
-                rope[knot_index] = prev_pos.clone();
+                rope[knot_index] = prev_pos;
             }
 
-            visited.insert(rope.last().unwrap().clone());
+            visited.insert(*rope.last().unwrap());
+            visual::record(|| rope_frame(&head_pos, &rope, &visited));
         }
     }
 
-    format!("{}", visited.len())
+    visited.len().into()
 }
 
-fn print_rope(rope: &Vec<Position>) {
-    let val = rope
+/// The knots on top of the positions the tail has visited, with up being up.
+fn rope_frame(head_pos: &Position, rope: &[Position], visited: &HashSet<Position>) -> Frame {
+    let at = |pos: &Position| Point::new(pos.x as i64, -pos.y as i64);
+
+    let visited = visited
         .iter()
-        .map(|t| t.to_string())
-        .collect::<Vec<String>>()
-        .join(",");
-    println!("[{val}]");
+        .map(|pos| (at(pos), Cell::new('#', Color::GRAY)));
+    let start = [(at(&Position::new(0, 0)), Cell::new('s', Color::WHITE))];
+    let knots = rope.iter().enumerate().rev().map(|(i, pos)| {
+        let glyph = char::from_digit(i as u32 + 1, 10).unwrap();
+        (at(pos), Cell::new(glyph, Color::SAND))
+    });
+    let head = [(at(head_pos), Cell::new('H', Color::RED))];
+
+    Frame::from_points(
+        visited.chain(start).chain(knots).chain(head),
+        Cell::new('.', Color::BLACK),
+    )
 }
 
 fn move_knot(head_pos: &Position, tail_pos: &Position) -> Position {
@@ -169,20 +188,20 @@ fn move_knot(head_pos: &Position, tail_pos: &Position) -> Position {
             x: tail_pos.x + if head_pos.x > tail_pos.x { 1 } else { -1 },
             y: tail_pos.y,
         }
-    } else if head_pos.dist(tail_pos) > 2f32 {
+    } else if dist(head_pos, tail_pos) > 2f32 {
         Position {
             x: tail_pos.x + if head_pos.x > tail_pos.x { 1 } else { -1 },
             y: tail_pos.y + if head_pos.y > tail_pos.y { 1 } else { -1 },
         }
     } else {
-        tail_pos.clone()
+        *tail_pos
     }
 }
 
 fn move_right(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
     match head_pos {
-        HeadPos::Same => (HeadPos::Right, tail_pos.clone()),
-        HeadPos::Above => (HeadPos::AboveRight, tail_pos.clone()),
+        HeadPos::Same => (HeadPos::Right, *tail_pos),
+        HeadPos::Above => (HeadPos::AboveRight, *tail_pos),
         HeadPos::AboveRight => (
             HeadPos::Right,
             Position {
@@ -204,21 +223,21 @@ fn move_right(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
                 y: tail_pos.y - 1,
             },
         ),
-        HeadPos::Below => (HeadPos::BelowRight, tail_pos.clone()),
-        HeadPos::BelowLeft => (HeadPos::Below, tail_pos.clone()),
-        HeadPos::Left => (HeadPos::Same, tail_pos.clone()),
-        HeadPos::AboveLeft => (HeadPos::Above, tail_pos.clone()),
+        HeadPos::Below => (HeadPos::BelowRight, *tail_pos),
+        HeadPos::BelowLeft => (HeadPos::Below, *tail_pos),
+        HeadPos::Left => (HeadPos::Same, *tail_pos),
+        HeadPos::AboveLeft => (HeadPos::Above, *tail_pos),
     }
 }
 
 fn move_left(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
     match head_pos {
-        HeadPos::Same => (HeadPos::Left, tail_pos.clone()),
-        HeadPos::Above => (HeadPos::AboveLeft, tail_pos.clone()),
-        HeadPos::AboveRight => (HeadPos::Above, tail_pos.clone()),
-        HeadPos::Right => (HeadPos::Same, tail_pos.clone()),
-        HeadPos::BelowRight => (HeadPos::Below, tail_pos.clone()),
-        HeadPos::Below => (HeadPos::BelowLeft, tail_pos.clone()),
+        HeadPos::Same => (HeadPos::Left, *tail_pos),
+        HeadPos::Above => (HeadPos::AboveLeft, *tail_pos),
+        HeadPos::AboveRight => (HeadPos::Above, *tail_pos),
+        HeadPos::Right => (HeadPos::Same, *tail_pos),
+        HeadPos::BelowRight => (HeadPos::Below, *tail_pos),
+        HeadPos::Below => (HeadPos::BelowLeft, *tail_pos),
         HeadPos::BelowLeft => (
             HeadPos::Left,
             Position {
@@ -245,7 +264,7 @@ fn move_left(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
 
 fn move_up(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
     match head_pos {
-        HeadPos::Same => (HeadPos::Above, tail_pos.clone()),
+        HeadPos::Same => (HeadPos::Above, *tail_pos),
         HeadPos::Above => (
             HeadPos::Above,
             Position {
@@ -260,11 +279,11 @@ fn move_up(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
                 y: tail_pos.y + 1,
             },
         ),
-        HeadPos::Right => (HeadPos::AboveRight, tail_pos.clone()),
-        HeadPos::BelowRight => (HeadPos::Right, tail_pos.clone()),
-        HeadPos::Below => (HeadPos::Same, tail_pos.clone()),
-        HeadPos::BelowLeft => (HeadPos::Left, tail_pos.clone()),
-        HeadPos::Left => (HeadPos::AboveLeft, tail_pos.clone()),
+        HeadPos::Right => (HeadPos::AboveRight, *tail_pos),
+        HeadPos::BelowRight => (HeadPos::Right, *tail_pos),
+        HeadPos::Below => (HeadPos::Same, *tail_pos),
+        HeadPos::BelowLeft => (HeadPos::Left, *tail_pos),
+        HeadPos::Left => (HeadPos::AboveLeft, *tail_pos),
         HeadPos::AboveLeft => (
             HeadPos::Above,
             Position {
@@ -277,10 +296,10 @@ fn move_up(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
 
 fn move_down(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
     match head_pos {
-        HeadPos::Same => (HeadPos::Below, tail_pos.clone()),
-        HeadPos::Above => (HeadPos::Same, tail_pos.clone()),
-        HeadPos::AboveRight => (HeadPos::Right, tail_pos.clone()),
-        HeadPos::Right => (HeadPos::BelowRight, tail_pos.clone()),
+        HeadPos::Same => (HeadPos::Below, *tail_pos),
+        HeadPos::Above => (HeadPos::Same, *tail_pos),
+        HeadPos::AboveRight => (HeadPos::Right, *tail_pos),
+        HeadPos::Right => (HeadPos::BelowRight, *tail_pos),
         HeadPos::BelowRight => (
             HeadPos::Below,
             Position {
@@ -302,7 +321,7 @@ fn move_down(head_pos: &HeadPos, tail_pos: &Position) -> (HeadPos, Position) {
                 y: tail_pos.y - 1,
             },
         ),
-        HeadPos::Left => (HeadPos::BelowLeft, tail_pos.clone()),
-        HeadPos::AboveLeft => (HeadPos::Left, tail_pos.clone()),
+        HeadPos::Left => (HeadPos::BelowLeft, *tail_pos),
+        HeadPos::AboveLeft => (HeadPos::Left, *tail_pos),
     }
 }
@@ -0,0 +1,16 @@
+//! Day 10 of 2022: Cathode-Ray Tube.
+//!
+//! Runs the program of a simple CPU, for its signal strengths and the letters it draws on a CRT.
+//!
+//! ```
+//! use day_2022_10::{parse, solve_part_one};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 13140.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,104 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer, PixelGrid,
+};
+
+/// An instruction of the CPU.
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    /// Does nothing for a cycle.
+    Noop,
+    /// Adds to the X register after two cycles.
+    AddX(i32),
+}
+
+/// Parses the program, one instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let p = Parser::new(input);
+    input.lines().map(|l| parse_line(&p, l)).collect()
+}
+
+/// An instruction, `noop` or `addx` with a number.
+pub fn parse_line<'a>(p: &Parser<'a>, l: &'a str) -> Result<Instruction, ParseError> {
+    if l == "noop" {
+        Ok(Instruction::Noop)
+    } else {
+        let num = p
+            .strip_prefix(l, "addx ")
+            .map_err(|_| p.error(l, "`noop` or `addx`"))?;
+        Ok(Instruction::AddX(p.number(num)?))
+    }
+}
+
+const INTERESTING_CYCLES: [u32; 6] = [20, 60, 100, 140, 180, 220];
+/// The sum of the signal strengths during the 20th, 60th, 100th, 140th, 180th and 220th cycles.
+pub fn solve_part_one(input: impl Iterator<Item = Instruction>) -> Answer {
+    let mut input = input;
+
+    let mut x = 1;
+    let mut next_cycle_val = None;
+
+    let mut sum = 0;
+
+    for cycle in 1..=220 {
+        if INTERESTING_CYCLES.contains(&(cycle as u32)) {
+            sum += x * cycle;
+        }
+
+        match next_cycle_val {
+            None => {
+                // The program may end early, or at a line that does not parse
+                let Some(ins) = input.next() else { break };
+                match ins {
+                    Instruction::Noop => {
+                        next_cycle_val = None;
+                    }
+                    Instruction::AddX(val) => {
+                        next_cycle_val = Some(val);
+                    }
+                }
+            }
+            Some(val) => {
+                x += val;
+                next_cycle_val = None;
+            }
+        }
+    }
+
+    sum.into()
+}
+
+/// The image the program draws on the CRT.
+pub fn solve_part_two(input: impl Iterator<Item = Instruction>) -> Answer {
+    let mut input = input;
+    let mut pos = 1;
+    let mut next_cycle_val = None;
+    let mut screen = PixelGrid::new(40, 6);
+
+    for cycle in 1..=240 {
+        let draw_pos = (cycle - 1) % 40;
+        let lit = draw_pos >= pos - 1 && draw_pos <= pos + 1;
+        screen.set(draw_pos as usize, ((cycle - 1) / 40) as usize, lit);
+
+        match next_cycle_val {
+            None => {
+                // The program may end early, or at a line that does not parse
+                let Some(ins) = input.next() else { break };
+                match ins {
+                    Instruction::Noop => {
+                        next_cycle_val = None;
+                    }
+                    Instruction::AddX(val) => {
+                        next_cycle_val = Some(val);
+                    }
+                }
+            }
+            Some(val) => {
+                pos += val;
+                next_cycle_val = None;
+            }
+        }
+    }
+
+    screen.into()
+}
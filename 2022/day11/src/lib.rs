@@ -0,0 +1,17 @@
+//! Day 11 of 2022: Monkey in the Middle.
+//!
+//! Simulates monkeys throwing items to each other by their worry level, see [`play_rounds`].
+//!
+//! ```
+//! use day_2022_11::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 10605.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 2713310158u64.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
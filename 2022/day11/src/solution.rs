@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+use aoc_utils::math::lcm_of;
+
+/// How a monkey decides which monkey to throw an item to.
+#[derive(Debug, Clone)]
+pub struct Test {
+    /// The worry level is checked for being divisible by this.
+    pub test_divisible_by: u64,
+    /// The monkey that gets the item if it is.
+    pub if_true_throw_to: u64,
+    /// The monkey that gets the item if it is not.
+    pub if_false_throw_to: u64,
+}
+
+/// How the worry level of an item changes when a monkey inspects it.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    /// `old + n`
+    Plus(u64),
+    /// `old + old`
+    PlusSelf,
+    /// `old * n`
+    Times(u64),
+    /// `old * old`
+    TimesSelf,
+}
+
+impl Operation {
+    /// The new worry level of an item with the worry level `old`.
+    pub fn apply(&self, old: u64) -> u64 {
+        match self {
+            Operation::Plus(v) => old + v,
+            Operation::PlusSelf => old + old,
+            Operation::Times(v) => old * v,
+            Operation::TimesSelf => old * old,
+        }
+    }
+}
+
+/// A monkey from the notes, numbered from 0 in the order of the input.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    /// The number of the monkey.
+    pub number: u64,
+    /// The worry levels of the items the monkey holds, in the order it inspects them.
+    pub starting_items: Vec<u64>,
+    /// How inspecting an item changes its worry level.
+    pub operation: Operation,
+    /// Where the monkey throws an item after inspecting it.
+    pub test: Test,
+}
+
+/// Parses the notes on the monkeys, which are separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .split("\n\n")
+        .map(|monkey_text| {
+            let monkey_lines = monkey_text.split('\n').collect::<Vec<&str>>();
+            let line = |index: usize, expected: &str| {
+                monkey_lines
+                    .get(index)
+                    .copied()
+                    .ok_or_else(|| p.error_after(monkey_text, expected))
+            };
+
+            let first = p.strip_suffix(line(0, "`Monkey`")?, ":")?;
+            let (_, number) = p.split_once(first, " ")?;
+            let number: u64 = p.number(number)?;
+
+            let (_, items) = p.split_once(line(1, "the starting items")?, ": ")?;
+            let starting_items = items
+                .trim()
+                .split(", ")
+                .map(|item| p.number::<u64>(item))
+                .collect::<Result<Vec<u64>, ParseError>>()?;
+
+            let (_, operation) = p.split_once(line(2, "the operation")?, " = old ")?;
+            let (operator, value) = p.split_once(operation, " ")?;
+            let operation = match (operator, value) {
+                ("*", "old") => Operation::TimesSelf,
+                ("*", num) => Operation::Times(p.number::<u64>(num)?),
+                ("+", "old") => Operation::PlusSelf,
+                ("+", num) => Operation::Plus(p.number::<u64>(num)?),
+                (op, _) => return Err(p.error(op, "`*` or `+`")),
+            };
+
+            let (_, test) = p.split_once(line(3, "the test")?, ": ")?;
+            let num = p.strip_prefix(test, "divisible by ")?;
+            let if_true = p.number::<u64>(p.strip_prefix(
+                line(4, "the true branch")?.trim(),
+                "If true: throw to monkey ",
+            )?)?;
+            let if_false = p.number::<u64>(p.strip_prefix(
+                line(5, "the false branch")?.trim(),
+                "If false: throw to monkey ",
+            )?)?;
+            let test = Test {
+                test_divisible_by: p.number(num)?,
+                if_true_throw_to: if_true,
+                if_false_throw_to: if_false,
+            };
+
+            Ok(Monkey {
+                number,
+                starting_items,
+                operation,
+                test,
+            })
+        })
+        .collect()
+}
+
+/// Plays `rounds` rounds of the monkeys inspecting and throwing their items, and returns how many
+/// items each monkey inspected, by monkey number.
+///
+/// `relief` is applied to the worry level of an item after a monkey inspects it, before the
+/// monkey decides where to throw it.
+pub fn play_rounds(monkeys: Vec<Monkey>, rounds: u32, relief: impl Fn(u64) -> u64) -> Vec<u64> {
+    let mut monkey_inspections = vec![0; monkeys.len()];
+    let mut monkeys: HashMap<u64, Monkey> = monkeys.into_iter().map(|m| (m.number, m)).collect();
+
+    for _round in 1..=rounds {
+        for monkey_index in 0..(monkeys.len() as u64) {
+            let monkey = monkeys.get(&monkey_index).unwrap();
+            let monkey_items = monkey.starting_items.to_owned();
+
+            let mut throw_to_map: HashMap<u64, Vec<u64>> = HashMap::new();
+
+            for item in monkey_items.into_iter() {
+                monkey_inspections[monkey.number as usize] += 1;
+
+                let new_item = relief(monkey.operation.apply(item));
+
+                let throw_to = if new_item.is_multiple_of(monkey.test.test_divisible_by) {
+                    monkey.test.if_true_throw_to
+                } else {
+                    monkey.test.if_false_throw_to
+                };
+
+                if let Some(vals) = throw_to_map.get_mut(&throw_to) {
+                    vals.push(new_item);
+                } else {
+                    throw_to_map.insert(throw_to, vec![new_item]);
+                }
+            }
+
+            monkeys.get_mut(&monkey_index).unwrap().starting_items = vec![];
+            for (monkey, items) in throw_to_map.into_iter() {
+                for item in items {
+                    monkeys.get_mut(&monkey).unwrap().starting_items.push(item);
+                }
+            }
+        }
+    }
+
+    monkey_inspections
+}
+
+/// The level of monkey business: the inspections of the two most active monkeys multiplied.
+pub fn monkey_business(mut inspections: Vec<u64>) -> u64 {
+    inspections.sort();
+    inspections.reverse();
+    let highest = inspections.first().unwrap();
+    let second_highest = inspections.get(1).unwrap();
+
+    highest * second_highest
+}
+
+/// The monkey business after 20 rounds, with the worry level divided by 3 after each inspection.
+pub fn solve_part_one(input: impl Iterator<Item = Monkey>) -> Answer {
+    let inspections = play_rounds(input.collect(), 20, |worry| worry / 3);
+
+    monkey_business(inspections).into()
+}
+
+/// The monkey business after 10000 rounds, without relief. The worry levels are kept modulo the
+/// least common multiple of the divisors, which does not change where the items are thrown.
+pub fn solve_part_two(input: impl Iterator<Item = Monkey>) -> Answer {
+    let monkeys: Vec<Monkey> = input.collect();
+
+    let test_vals = monkeys
+        .iter()
+        .map(|m| m.test.test_divisible_by)
+        .collect::<Vec<u64>>();
+    let lcm = lcm_of(test_vals);
+
+    let inspections = play_rounds(monkeys, 10_000, |worry| worry % lcm);
+
+    monkey_business(inspections).into()
+}
@@ -0,0 +1,29 @@
+//! Day 12 of 2022: Hill Climbing Algorithm.
+//!
+//! Finds the fewest steps up a height map with Dijkstra's algorithm, see [`path_find`].
+//!
+//! ```
+//! use day_2022_12::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 31.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap()), 29.into());
+//! ```
+//!
+//! [`path_find`] also works on a map of its own:
+//!
+//! ```
+//! use aoc_utils::{Grid, Point};
+//! use day_2022_12::path_find;
+//!
+//! // Up one at a time from the left, the wall of 9 is too high to climb
+//! let map = Grid::from_fn(4, 1, |pos| [0, 1, 2, 9][pos.x]);
+//! assert_eq!(path_find(Point::new(0, 0), &Point::new(2, 0), &map), 2);
+//! assert_eq!(path_find(Point::new(0, 0), &Point::new(3, 0), &map), usize::MAX);
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
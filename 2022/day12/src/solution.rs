@@ -0,0 +1,189 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use aoc::{
+    parse::{ParseError, Parser},
+    visual::{self, Cell, Color, Frame},
+    Answer,
+};
+use aoc_utils::{Grid, Point};
+
+/// A square waiting to be visited by [`path_find`], ordered so the cheapest one comes out of a
+/// [`BinaryHeap`] first.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QueueEntry {
+    pos: Position,
+    height: u8,
+    cost: usize,
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A square of the height map.
+pub type Position = Point<usize>;
+
+/// The height map, with where the climb starts and where it should end.
+#[derive(Debug, Clone)]
+pub struct Map {
+    /// The square marked `S`.
+    pub start_pos: Position,
+    /// The square marked `E`.
+    pub destination: Position,
+    /// The heights, from 0 for `a` to 25 for `z`.
+    pub map: Grid<u8>,
+}
+
+/// Parses the height map, where `S` is at height `a` and `E` at height `z`.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let p = Parser::new(input);
+    let mut start_pos: Option<Position> = None;
+    let mut destination: Option<Position> = None;
+
+    let map = p.grid("squares", |c, pos| match c.as_bytes()[0] {
+        b'S' => {
+            start_pos = Some(pos);
+            Ok(0)
+        }
+        b'E' => {
+            destination = Some(pos);
+            Ok(b'z' - b'a')
+        }
+        b @ b'a'..=b'z' => Ok(b - b'a'),
+        _ => Err(p.error(c, "a height from `a` to `z`, `S` or `E`")),
+    })?;
+
+    let end = &input[input.len()..];
+    Ok(Map {
+        start_pos: start_pos.ok_or_else(|| p.error(end, "a start position `S`"))?,
+        destination: destination.ok_or_else(|| p.error(end, "a destination `E`"))?,
+        map,
+    })
+}
+
+/// The fewest steps from `start_pos` to `dest_pos` with Dijkstra's algorithm, going at most one
+/// higher with each step but down as far as needed. Returns `usize::MAX` if the destination can
+/// not be reached.
+#[inline(always)]
+pub fn path_find(start_pos: Position, dest_pos: &Position, map: &Grid<u8>) -> usize {
+    let mut heap: BinaryHeap<QueueEntry> = BinaryHeap::new();
+    let mut dist_map: HashMap<Position, usize> = HashMap::new();
+    let recording = visual::is_recording();
+    // Where each square was reached from, only kept to draw the path when recording
+    let mut prev_map: HashMap<Position, Position> = HashMap::new();
+
+    for pos in map.points() {
+        dist_map.insert(pos, usize::MAX);
+    }
+
+    dist_map.insert(start_pos, 0);
+    heap.push(QueueEntry {
+        pos: start_pos,
+        height: map[start_pos],
+        cost: 0,
+    });
+
+    let mut cost = 0;
+    while let Some(qe) = heap.pop() {
+        if qe.cost > cost {
+            cost = qe.cost;
+            visual::record(|| search_frame(map, start_pos, dest_pos, &dist_map, &[]));
+        }
+
+        if qe.pos == *dest_pos {
+            break;
+        }
+
+        if qe.cost > dist_map[&qe.pos] {
+            // We have found a better alternative for this pos
+            continue;
+        }
+
+        for next_pos in map.neighbours(qe.pos) {
+            let height = map[next_pos];
+            if height <= qe.height + 1 {
+                let next = QueueEntry {
+                    pos: next_pos,
+                    height,
+                    cost: qe.cost + 1,
+                };
+
+                if next.cost < dist_map[&next_pos] {
+                    heap.push(next);
+                    dist_map.insert(next_pos, next.cost);
+                    if recording {
+                        prev_map.insert(next_pos, qe.pos);
+                    }
+                }
+            }
+        }
+    }
+
+    if recording {
+        let mut path = vec![*dest_pos];
+        while let Some(prev) = prev_map.get(path.last().unwrap()) {
+            path.push(*prev);
+        }
+        visual::record(|| search_frame(map, start_pos, dest_pos, &dist_map, &path));
+    }
+
+    dist_map[dest_pos]
+}
+
+/// The heights, with the squares the search has reached and the path it found.
+fn search_frame(
+    map: &Grid<u8>,
+    start_pos: Position,
+    dest_pos: &Position,
+    dist_map: &HashMap<Position, usize>,
+    path: &[Position],
+) -> Frame {
+    Frame::new(Grid::from_fn(map.width(), map.height(), |pos| {
+        let height = map[pos];
+        let shade = Color::gray(40 + height * 8);
+        if pos == start_pos {
+            Cell::new('S', Color::GREEN)
+        } else if pos == *dest_pos {
+            Cell::new('E', Color::RED)
+        } else if path.contains(&pos) {
+            Cell::new('#', Color::YELLOW)
+        } else if dist_map[&pos] != usize::MAX {
+            Cell::new('.', Color::rgb(shade.r / 3, shade.g / 2, shade.b))
+        } else {
+            Cell::new((b'a' + height) as char, shade)
+        }
+    }))
+}
+
+/// The fewest steps from `S` to `E`.
+pub fn solve_part_one(input: Map) -> Answer {
+    let steps = path_find(input.start_pos, &input.destination, &input.map);
+
+    // Should have reached goal!
+    steps.into()
+}
+
+/// The fewest steps to `E` from any square at height `a`.
+pub fn solve_part_two(input: Map) -> Answer {
+    let mut shortest_path = usize::MAX;
+
+    for (pos, &height) in input.map.iter() {
+        if height == 0 {
+            let steps = path_find(pos, &input.destination, &input.map);
+
+            if steps < shortest_path {
+                shortest_path = steps;
+            }
+        }
+    }
+
+    shortest_path.into()
+}
@@ -0,0 +1,28 @@
+//! Day 13 of 2022: Distress Signal.
+//!
+//! Compares packets of nested lists and integers, see [`compare_values`].
+//!
+//! ```
+//! use day_2022_13::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap().into_iter()), 13.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap().into_iter()), 140.into());
+//! ```
+//!
+//! [`compare_values`] also compares packets built by hand:
+//!
+//! ```
+//! use day_2022_13::{compare_values, Value};
+//!
+//! let list = |values: Vec<u32>| Value::List(values.into_iter().map(Value::Int).collect());
+//! assert_eq!(compare_values(&list(vec![1, 1, 3]), &list(vec![1, 1, 5])), Some(true));
+//! assert_eq!(compare_values(&list(vec![7, 7]), &list(vec![7])), Some(false));
+//! assert_eq!(compare_values(&Value::Int(4), &list(vec![4])), None);
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::handle_day(2022, 13);
+}
@@ -0,0 +1,196 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// A packet, or a value in one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// An integer.
+    Int(u32),
+    /// A list of values, which may be empty.
+    List(Vec<Value>),
+}
+
+impl Value {
+    /// The value written the way the input does.
+    pub fn to_str(&self) -> String {
+        match self {
+            Value::Int(a) => a.to_string(),
+            Value::List(vals) => format!(
+                "[{}]",
+                vals.iter()
+                    .map(|v| v.to_str())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+        }
+    }
+}
+
+/// Two packets from the input.
+#[derive(Debug, Clone)]
+pub struct Pair {
+    /// The place of the pair in the input, from 1.
+    pub index: u32,
+    /// The packet on the left.
+    pub first: Value,
+    /// The packet on the right.
+    pub second: Value,
+}
+
+/// Parses the pairs of packets, which are separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .trim_end()
+        .split("\n\n")
+        .enumerate()
+        .map(|(index, pair)| {
+            let (first, second) = p.split_once(pair, "\n")?;
+
+            Ok(Pair {
+                index: index as u32 + 1,
+                first: parse_packet(&p, first)?,
+                second: parse_packet(&p, second)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_packet<'a>(p: &Parser<'a>, line: &'a str) -> Result<Value, ParseError> {
+    if !line.starts_with('[') {
+        return Err(p.error(line, "`[`"));
+    }
+
+    let (packet, rest) = parse_value(p, line)?;
+    if !rest.is_empty() {
+        return Err(p.error(rest, "end of line"));
+    }
+
+    Ok(packet)
+}
+
+/// Parses the value at the start of `s`, returning it along with what comes after it.
+fn parse_value<'a>(p: &Parser<'a>, s: &'a str) -> Result<(Value, &'a str), ParseError> {
+    if let Some(mut rest) = s.strip_prefix('[') {
+        let mut list: Vec<Value> = vec![];
+        if let Some(rest) = rest.strip_prefix(']') {
+            return Ok((Value::List(list), rest));
+        }
+
+        loop {
+            let (value, after) = parse_value(p, rest)?;
+            list.push(value);
+
+            if let Some(after) = after.strip_prefix(',') {
+                rest = after;
+            } else if let Some(after) = after.strip_prefix(']') {
+                return Ok((Value::List(list), after));
+            } else {
+                return Err(p.error(after, "`,` or `]`"));
+            }
+        }
+    }
+
+    let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if digits == 0 {
+        return Err(p.error(s, "a number or `[`"));
+    }
+
+    Ok((Value::Int(p.number(&s[..digits])?), &s[digits..]))
+}
+
+/// Whether `left` and `right` are in the right order: `Some(true)` if `left` comes first,
+/// `Some(false)` if `right` does and `None` if the comparison can't decide. An integer compared
+/// with a list is compared as a list of only that integer.
+pub fn compare_values(left: &Value, right: &Value) -> Option<bool> {
+    match (left, right) {
+        (Value::Int(l), Value::Int(r)) => {
+            if l != r {
+                return Some(l < r);
+            }
+        }
+        (Value::List(_), Value::Int(_)) => {
+            return compare_values(left, &Value::List(vec![right.clone()]))
+        }
+        (Value::Int(_), Value::List(_)) => {
+            return compare_values(&Value::List(vec![left.clone()]), right)
+        }
+        (Value::List(l), Value::List(r)) => {
+            let mut r = r.iter();
+            for left_val in l {
+                if let Some(right_val) = r.next() {
+                    if let Some(v) = compare_values(left_val, right_val) {
+                        return Some(v);
+                    }
+                } else {
+                    // Left list was longer
+                    return Some(false);
+                }
+            }
+
+            if r.next().is_some() {
+                // Right list was longer
+                return Some(true);
+            }
+        }
+    }
+
+    None
+}
+
+/// The sum of the indices of the pairs that are in the right order.
+pub fn solve_part_one(input: impl Iterator<Item = Pair>) -> Answer {
+    let sum = input
+        .map(|pair| {
+            let in_order = compare_values(&pair.first, &pair.second);
+
+            if in_order.expect("Failed to calc list") {
+                pair.index as i32
+            } else {
+                -1
+            }
+        })
+        .filter(|&index| index > 0)
+        .sum::<i32>();
+
+    sum.into()
+}
+
+/// The decoder key: the places of the divider packets `[[2]]` and `[[6]]` multiplied, once all
+/// packets are sorted.
+pub fn solve_part_two(input: impl Iterator<Item = Pair>) -> Answer {
+    let key_a = Value::List(vec![Value::List(vec![Value::Int(2)])]);
+    let key_b = Value::List(vec![Value::List(vec![Value::Int(6)])]);
+
+    let mut lines: Vec<Value> = vec![key_a.clone(), key_b.clone()];
+
+    for pair in input {
+        lines.push(pair.first);
+        lines.push(pair.second);
+    }
+
+    lines.sort_by(|a, b| {
+        let res = compare_values(a, b);
+        match res {
+            Some(true) => std::cmp::Ordering::Less,
+            Some(false) => std::cmp::Ordering::Greater,
+            None => std::cmp::Ordering::Equal,
+        }
+    });
+
+    let mut key_a_index = 0;
+    let mut key_b_index = 0;
+    for (index, l) in lines.into_iter().enumerate() {
+        if l == key_a {
+            key_a_index = index + 1;
+        }
+
+        if l == key_b {
+            key_b_index = index + 1;
+        }
+    }
+
+    (key_a_index * key_b_index).into()
+}
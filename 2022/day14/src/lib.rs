@@ -0,0 +1,18 @@
+//! Day 14 of 2022: Regolith Reservoir.
+//!
+//! Pours sand into a cave of rock until it falls into the abyss, or until it fills up to the source
+//! when the cave has a floor.
+//!
+//! ```
+//! use day_2022_14::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 24.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap()), 93.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -1,6 +1,14 @@
+use aoc::{
+    parse::{ParseError, Parser},
+    visual::{self, Cell, Color, Frame},
+    Answer,
+};
+use aoc_utils::{Grid, Point};
+
+/// The cave, with the rock and the sand that has come to rest in it.
 #[derive(Clone, Debug)]
 pub struct Map {
-    map: Vec<Vec<bool>>,
+    map: Grid<bool>,
     start_x: u32,
     width: u32,
     height: u32,
@@ -9,33 +17,47 @@ pub struct Map {
 impl Map {
     #[inline(always)]
     fn get_at(&self, x: u32, y: u32) -> bool {
-        self.map[y as usize][(x - self.start_x) as usize]
+        self.map[self.point(x, y)]
     }
 
     #[inline(always)]
     fn set_at(&mut self, x: u32, y: u32, val: bool) {
-        self.map[y as usize][(x - self.start_x) as usize] = val;
+        let point = self.point(x, y);
+        self.map[point] = val;
     }
 
-    fn print_map(&self) {
-        for y in 0..self.height {
-            let mut row_str = String::new();
-            for x in 0..self.width {
-                row_str = format!(
-                    "{row_str}{}",
-                    if self.map[y as usize][x as usize] {
-                        "#"
-                    } else {
-                        "."
-                    }
-                );
+    #[inline(always)]
+    fn point(&self, x: u32, y: u32) -> Point<usize> {
+        Point::new((x - self.start_x) as usize, y as usize)
+    }
+
+    /// Filled cells that are not in `rock` are sand.
+    fn frame(&self, rock: &Grid<bool>) -> Frame {
+        let cells = Grid::from_fn(self.map.width(), self.map.height(), |p| {
+            if p.y == 0 && p.x + self.start_x as usize == 500 {
+                Cell::new('+', Color::YELLOW)
+            } else if !self.map[p] {
+                Cell::new('.', Color::BLACK)
+            } else if rock.get(p).copied().unwrap_or(false) {
+                Cell::new('#', Color::GRAY)
+            } else {
+                Cell::new('o', Color::SAND)
             }
-            println!("{row_str}");
-        }
+        });
+        Frame::new(cells).at(Point::new(self.start_x as i64, 0))
     }
 }
 
-pub fn parse<'a>(input: &str) -> Map {
+/// Records the map, `rock` is the map before any sand fell and only kept while recording.
+fn record(map: &Map, rock: &Option<Grid<bool>>) {
+    if let Some(rock) = rock {
+        visual::record(|| map.frame(rock));
+    }
+}
+
+/// Parses the paths of rock, one per line, into a cave just big enough to hold them.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let p = Parser::new(input);
     let mut min_x = u32::MAX;
     let mut max_x = 0;
     let mut min_y = u32::MAX;
@@ -44,11 +66,16 @@ pub fn parse<'a>(input: &str) -> Map {
     let coords = input
         .lines()
         .map(|l| {
+            let mut prev: Option<(u32, u32)> = None;
             l.split(" -> ")
                 .map(|coord| {
-                    let (x, y) = coord.split_once(",").unwrap();
-                    let x = x.parse::<u32>().unwrap();
-                    let y = y.parse::<u32>().unwrap();
+                    let (x, y) = p.split_once(coord, ",")?;
+                    let x = p.number::<u32>(x)?;
+                    let y = p.number::<u32>(y)?;
+                    if prev.is_some_and(|(prev_x, prev_y)| prev_x != x && prev_y != y) {
+                        return Err(p.error(coord, "a point in line with the previous one"));
+                    }
+                    prev = Some((x, y));
 
                     if x < min_x {
                         min_x = x;
@@ -63,17 +90,17 @@ pub fn parse<'a>(input: &str) -> Map {
                         max_y = y;
                     }
 
-                    (x, y)
+                    Ok((x, y))
                 })
-                .collect::<Vec<(u32, u32)>>()
+                .collect::<Result<Vec<(u32, u32)>, ParseError>>()
         })
-        .collect::<Vec<Vec<(u32, u32)>>>();
+        .collect::<Result<Vec<Vec<(u32, u32)>>, ParseError>>()?;
 
     let width = max_x + 1 - min_x;
     let height = max_y + 1;
 
     let mut map = Map {
-        map: vec![vec![false; width as usize]; height as usize],
+        map: Grid::new(width as usize, height as usize, false),
         start_x: min_x,
         width,
         height,
@@ -98,7 +125,7 @@ pub fn parse<'a>(input: &str) -> Map {
                         map.set_at(x, y, true);
                     }
                 } else {
-                    panic!("Unable to draw diagonal lines!");
+                    unreachable!("parse rejects diagonal lines");
                 }
             }
 
@@ -107,14 +134,15 @@ pub fn parse<'a>(input: &str) -> Map {
         }
     }
 
-    map
+    Ok(map)
 }
 
 fn find_sand_count_with_abyss(map: &mut Map) -> u32 {
+    let rock = visual::is_recording().then(|| map.map.clone());
     let mut sand_count = 0;
     'outer: loop {
         // println!("Sand no {round}");
-        // map.print_map();
+        record(map, &rock);
 
         let mut sand_x = 500u32;
         for sand_y in 0..(map.height - 1) {
@@ -128,7 +156,7 @@ fn find_sand_count_with_abyss(map: &mut Map) -> u32 {
                 // println!("Sand below! Trying left");
 
                 // Can't move downwards, try down-left
-                sand_x = sand_x - 1;
+                sand_x -= 1;
                 if sand_x < map.start_x {
                     // Sand will end up outside of map!
                     return sand_count;
@@ -136,7 +164,7 @@ fn find_sand_count_with_abyss(map: &mut Map) -> u32 {
 
                 if map.get_at(sand_x, check_y) {
                     // didn't work, try down-right
-                    sand_x = sand_x + 2;
+                    sand_x += 2;
 
                     if sand_x > map.start_x + map.width {
                         // Sand will end up outside of map!
@@ -148,7 +176,7 @@ fn find_sand_count_with_abyss(map: &mut Map) -> u32 {
 
                         // There are no more places to move, stop
                         map.set_at(sand_x - 1, sand_y, true);
-                        sand_count = sand_count + 1;
+                        sand_count += 1;
                         continue 'outer;
                     }
                 }
@@ -158,19 +186,21 @@ fn find_sand_count_with_abyss(map: &mut Map) -> u32 {
         return sand_count;
     }
 }
-pub fn solve_part_one<'a>(input: Map) -> String {
+/// How much sand comes to rest before it starts falling into the abyss.
+pub fn solve_part_one(input: Map) -> Answer {
     let mut map = input;
     let sand_count = find_sand_count_with_abyss(&mut map);
 
-    format!("{sand_count}")
+    sand_count.into()
 }
 
 fn find_sand_count_with_floor(map: &mut Map) -> u32 {
+    let rock = visual::is_recording().then(|| map.map.clone());
     let mut sand_count = 0;
-    'outer: while map.get_at(500, 0) == false {
-        // map.print_map();
+    'outer: while !map.get_at(500, 0) {
+        record(map, &rock);
 
-        sand_count = sand_count + 1;
+        sand_count += 1;
 
         let mut sand_x = 500u32;
         for sand_y in 0..(map.height - 1) {
@@ -179,12 +209,12 @@ fn find_sand_count_with_floor(map: &mut Map) -> u32 {
             // println!("Getting at ({sand_x},{check_y})");
             if map.get_at(sand_x, check_y) {
                 // Can't move downwards, try down-left
-                sand_x = sand_x - 1;
+                sand_x -= 1;
 
                 // println!("Getting at ({sand_x},{check_y})");
                 if map.get_at(sand_x, check_y) {
                     // didn't work, try down-right
-                    sand_x = sand_x + 2;
+                    sand_x += 2;
 
                     // println!("Getting at ({sand_x},{check_y})");
                     if map.get_at(sand_x, check_y) {
@@ -199,18 +229,20 @@ fn find_sand_count_with_floor(map: &mut Map) -> u32 {
         return sand_count;
     }
 
+    record(map, &rock);
     sand_count
 }
 
-pub fn solve_part_two<'a>(input: Map) -> String {
+/// How much sand comes to rest on the floor before the source is blocked.
+pub fn solve_part_two(input: Map) -> Answer {
     // let width = input.start_x * 2 + input.width;
     let width = input.start_x * 2 + input.width;
     let height = input.height;
 
     let mut map = Map {
-        map: vec![vec![false; width as usize]; height as usize],
+        map: Grid::new(width as usize, height as usize + 2, false),
         start_x: 0,
-        width: width,
+        width,
         height: height + 2,
     };
 
@@ -222,10 +254,11 @@ pub fn solve_part_two<'a>(input: Map) -> String {
     }
 
     // Add floor
-    map.map.push(vec![false; width as usize]);
-    map.map.push(vec![true; width as usize]);
+    for x in 0..width {
+        map.set_at(x, height + 1, true);
+    }
 
     let sand_count = find_sand_count_with_floor(&mut map);
 
-    format!("{sand_count}")
+    sand_count.into()
 }
@@ -0,0 +1,18 @@
+//! Day 15 of 2022: Beacon Exclusion Zone.
+//!
+//! Works out where the sensors rule out the distress beacon, and finds the one position in the
+//! search area none of them cover.
+//!
+//! ```
+//! use day_2022_15::{parse, solve_part_one};
+//!
+//! // A sensor on the row that is checked, with its beacon two positions to the right
+//! let input = "Sensor at x=0, y=2000000: closest beacon is at x=2, y=2000000";
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 4.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
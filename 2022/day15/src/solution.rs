@@ -0,0 +1,227 @@
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+
+use aoc::{
+    parse::{ParseError, Parser},
+    visual::{self, Cell, Color, Frame},
+    Answer,
+};
+use aoc_utils::{Grid, Point};
+
+/// A position in the cave.
+pub type Position = Point<i64>;
+
+fn parse_position<'a>(p: &Parser<'a>, coord: &'a str) -> Result<Position, ParseError> {
+    let (x_str, y_str) = p.split_once(coord, ", ")?;
+    let x_str = p.strip_prefix(x_str, "x=")?;
+    let y_str = p.strip_prefix(y_str, "y=")?;
+
+    Ok(Position::new(p.number(x_str)?, p.number(y_str)?))
+}
+
+/// The sensors, with the closest beacon each one found.
+#[derive(Debug, Clone)]
+pub struct Map {
+    sensor_beacon_map: HashMap<Position, Position>,
+}
+
+/// Parses the sensors and their closest beacons, one per line.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let p = Parser::new(input);
+    let mut map = HashMap::new();
+
+    for l in input.lines() {
+        let without_prefix = p.strip_prefix(l, "Sensor at ")?;
+        let (sensor, beacon) = p.split_once(without_prefix, ": closest beacon is at ")?;
+        map.insert(parse_position(&p, sensor)?, parse_position(&p, beacon)?);
+    }
+
+    Ok(Map {
+        sensor_beacon_map: map,
+    })
+}
+
+const WANTED_ROW: i64 = 2_000_000;
+const EDGE: i64 = 9_000_000;
+/// How many positions in the row `y=2000000` can not have a beacon.
+pub fn solve_part_one(input: Map) -> Answer {
+    // The part of the row each sensor covers, as a range of x
+    let mut covered: Vec<(i64, i64)> = input
+        .sensor_beacon_map
+        .iter()
+        .filter_map(|(sensor, beacon)| {
+            let reach = sensor.manhattan_distance(*beacon) - (sensor.y - WANTED_ROW).abs();
+            let from = max(sensor.x - reach, -EDGE);
+            let to = min(sensor.x + reach, EDGE - 1);
+            (reach >= 0 && from <= to).then_some((from, to))
+        })
+        .collect();
+    covered.sort();
+
+    let mut count = 0;
+    let mut next = -EDGE;
+    for (from, to) in covered {
+        let from = max(from, next);
+        if from <= to {
+            count += to + 1 - from;
+            next = to + 1;
+        }
+    }
+
+    // Beacons are always covered by their sensor
+    let mut beacons: Vec<i64> = input
+        .sensor_beacon_map
+        .values()
+        .filter(|beacon| beacon.y == WANTED_ROW && (-EDGE..EDGE).contains(&beacon.x))
+        .map(|beacon| beacon.x)
+        .collect();
+    beacons.sort();
+    beacons.dedup();
+
+    (count as u64 - beacons.len() as u64).into()
+}
+
+/// Part 1 by checking every position of the row against every sensor, for checking
+/// [`solve_part_one`] against.
+pub fn reference_part_one(input: Map) -> Answer {
+    let sum: u64 = (-EDGE..EDGE)
+        .map(|x| {
+            let pos = Position::new(x, WANTED_ROW);
+            if input
+                .sensor_beacon_map
+                .values()
+                .any(|beacon| *beacon == pos)
+            {
+                // Can't exist a beacon where there already is one
+                return 0;
+            }
+
+            let mut count_x = false;
+            for (sensor, beacon) in input.sensor_beacon_map.iter() {
+                if sensor.manhattan_distance(pos) <= sensor.manhattan_distance(*beacon) {
+                    count_x = true;
+                    break;
+                }
+            }
+
+            if count_x {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    sum.into()
+}
+
+const SEARCH_AREA_EDGE: i64 = 4000000;
+// const SEARCH_AREA_EDGE: i64 = 20;
+fn find_coord(sensor_beacon_map: &HashMap<Position, Position>) -> Position {
+    for (sensor, beacon) in sensor_beacon_map.iter() {
+        let dist = sensor.manhattan_distance(*beacon);
+        let from = max(sensor.x - dist - 1, 0);
+        let to = min(sensor.x + dist + 1, SEARCH_AREA_EDGE);
+
+        for x in from..=to {
+            let above_y = min(sensor.y + dist + 1 - (sensor.x - x).abs(), SEARCH_AREA_EDGE);
+            let above_pos = Position { x, y: above_y };
+
+            let mut is_above = true;
+            for (inner_sensor, inner_beacon) in sensor_beacon_map.iter() {
+                let inner_dist = inner_sensor.manhattan_distance(*inner_beacon);
+                let sensor_pos_dist = inner_sensor.manhattan_distance(above_pos);
+                if sensor_pos_dist <= inner_dist {
+                    is_above = false;
+                }
+            }
+            if is_above {
+                return above_pos;
+            }
+
+            let below_y = max(sensor.y - (dist + 1 - (sensor.x - x).abs()), 0);
+            let below_pos = Position { x, y: below_y };
+
+            let mut is_below = true;
+            for (inner_sensor, inner_beacon) in sensor_beacon_map.iter() {
+                let inner_dist = inner_sensor.manhattan_distance(*inner_beacon);
+                let sensor_pos_dist = inner_sensor.manhattan_distance(below_pos);
+                if sensor_pos_dist <= inner_dist {
+                    is_below = false;
+                }
+            }
+            if is_below {
+                return below_pos;
+            }
+        }
+    }
+
+    panic!("Failed to find position :(");
+}
+
+/// How many cells wide and high the frames of the search area are.
+const FRAME_SIZE: i64 = 80;
+
+/// The search area scaled down to [`FRAME_SIZE`], with the area the sensors cover filled in.
+fn coverage_frame(sensors: &[(&Position, &Position)], found: Option<Position>) -> Frame {
+    let step = (SEARCH_AREA_EDGE + 1) / FRAME_SIZE;
+    // Positions left of or above the search area are not drawn
+    let to_cell = |pos: &Position| {
+        (pos.x >= 0 && pos.y >= 0)
+            .then(|| Point::new((pos.x / step) as usize, (pos.y / step) as usize))
+    };
+
+    let mut cells = Grid::from_fn(FRAME_SIZE as usize, FRAME_SIZE as usize, |cell| {
+        let pos = Position::new(
+            cell.x as i64 * step + step / 2,
+            cell.y as i64 * step + step / 2,
+        );
+        let covered = sensors.iter().any(|(sensor, beacon)| {
+            sensor.manhattan_distance(pos) <= sensor.manhattan_distance(**beacon)
+        });
+        if covered {
+            Cell::new('#', Color::BLUE)
+        } else {
+            Cell::new('.', Color::BLACK)
+        }
+    });
+
+    for (sensor, beacon) in sensors {
+        for (pos, cell) in [
+            (sensor, Cell::new('S', Color::YELLOW)),
+            (beacon, Cell::new('B', Color::GREEN)),
+        ] {
+            if let Some(target) = to_cell(pos).and_then(|pos| cells.get_mut(pos)) {
+                *target = cell;
+            }
+        }
+    }
+    if let Some(pos) = found {
+        if let Some(target) = to_cell(&pos).and_then(|pos| cells.get_mut(pos)) {
+            *target = Cell::new('X', Color::RED);
+        }
+    }
+
+    Frame::new(cells)
+}
+
+/// The tuning frequency of the only position from 0 to 4000000 on both axes that no sensor
+/// covers.
+pub fn solve_part_two(input: Map) -> Answer {
+    let mut sensors: Vec<(&Position, &Position)> = Vec::new();
+    if visual::is_recording() {
+        sensors = input.sensor_beacon_map.iter().collect();
+        sensors.sort();
+        for covered in 1..=sensors.len() {
+            visual::record(|| coverage_frame(&sensors[..covered], None));
+        }
+    }
+
+    let pos = find_coord(&input.sensor_beacon_map);
+    visual::record(|| coverage_frame(&sensors, Some(pos)));
+
+    let val = pos.x * SEARCH_AREA_EDGE + pos.y;
+    val.into()
+}
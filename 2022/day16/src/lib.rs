@@ -0,0 +1,17 @@
+//! Day 16 of 2022: Proboscidea Volcanium.
+//!
+//! Opens valves to release the most pressure in 30 minutes. Neither part is solved yet.
+//!
+//! ```
+//! use day_2022_16::parse;
+//!
+//! let valves = parse(include_str!("../test.txt")).unwrap();
+//! assert_eq!(valves.flow_rate_map["BB"], 13);
+//! assert_eq!(valves.tunnel_map["BB"], ["CC", "AA"]);
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -1,27 +1,33 @@
 use std::collections::HashMap;
 
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// The scan of the valves and the tunnels between them.
 #[derive(Debug, Clone)]
 pub struct Valves {
-    tunnel_map: HashMap<String, Vec<String>>,
-    flow_rate_map: HashMap<String, u32>,
+    /// The valves each valve has tunnels to, by name.
+    pub tunnel_map: HashMap<String, Vec<String>>,
+    /// The flow rate of each valve, by name.
+    pub flow_rate_map: HashMap<String, u32>,
 }
 
-pub fn parse<'a>(input: &str) -> Valves {
+/// Parses the scan, one valve per line.
+pub fn parse(input: &str) -> Result<Valves, ParseError> {
+    let p = Parser::new(input);
     let mut tunnel_map = HashMap::new();
     let mut flow_rate_map = HashMap::new();
 
-    input.lines().for_each(|l| {
-        let (valve, rest) = l
-            .strip_prefix("Valve ")
-            .unwrap()
-            .split_once(" has flow rate=")
-            .unwrap();
+    for l in input.lines() {
+        let (valve, rest) = p.split_once(p.strip_prefix(l, "Valve ")?, " has flow rate=")?;
 
-        let (flow_rate, rest) = rest.split_once("; ").unwrap();
+        let (flow_rate, rest) = p.split_once(rest, "; ")?;
         let tunnels_to = if let Some(singular) = rest.strip_prefix("tunnel leads to valve") {
             singular
         } else {
-            rest.strip_prefix("tunnels lead to valves ").unwrap()
+            p.strip_prefix(rest, "tunnels lead to valves ")?
         };
 
         let tunnels_to = tunnels_to
@@ -29,13 +35,13 @@ pub fn parse<'a>(input: &str) -> Valves {
             .map(|s| s.trim().to_string())
             .collect::<Vec<String>>();
         tunnel_map.insert(valve.to_string(), tunnels_to);
-        flow_rate_map.insert(valve.to_string(), flow_rate.parse().unwrap());
-    });
+        flow_rate_map.insert(valve.to_string(), p.number(flow_rate)?);
+    }
 
-    Valves {
+    Ok(Valves {
         tunnel_map,
         flow_rate_map,
-    }
+    })
 }
 
 const INITIAL_MINUTES: u32 = 30;
@@ -52,7 +58,7 @@ fn calculate_maximum_throughput(
     }
 
     let mut highest_option = 0;
-    if open_valves.contains(&curr_pos) == false {
+    if !open_valves.contains(curr_pos) {
         let mut open_valves = open_valves.clone();
         open_valves.push(curr_pos.clone());
         let preassure_released = valves.flow_rate_map[curr_pos] * minutes_remaining;
@@ -73,14 +79,18 @@ fn calculate_maximum_throughput(
     highest_option
 }
 
-pub fn solve_part_one<'a>(input: Valves) -> String {
+/// Not solved yet.
+// The search below is a start, which is kept while part 1 is being worked on
+#[allow(unreachable_code, unused_variables)]
+pub fn solve_part_one(input: Valves) -> Answer {
     todo!("Finish part 1");
     let throughput =
         calculate_maximum_throughput(&input, INITIAL_MINUTES, &"AA".to_string(), &vec![]);
 
-    format!("{throughput}")
+    throughput.into()
 }
 
-pub fn solve_part_two<'a>(input: Valves) -> String {
+/// Not solved yet.
+pub fn solve_part_two(_input: Valves) -> Answer {
     todo!("Part two is not yet implemented");
 }
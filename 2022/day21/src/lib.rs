@@ -0,0 +1,18 @@
+//! Day 21 of 2022: Monkey Math.
+//!
+//! Evaluates what the root monkey yells, and solves for the number the human has to yell for the
+//! root's numbers to match.
+//!
+//! ```
+//! use day_2022_21::{parse, solve_part_one, solve_part_two};
+//!
+//! let input = include_str!("../test.txt");
+//! assert_eq!(solve_part_one(parse(input).unwrap()), 152.into());
+//! assert_eq!(solve_part_two(parse(input).unwrap()), 301.into());
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use aoc::{
+    parse::{ParseError, Parser},
+    Answer,
+};
+
+/// The job of a monkey.
+#[derive(Debug, Clone)]
+pub enum Monkey {
+    /// Yells a number.
+    Number(i64),
+    /// Yells the sum of what two monkeys yell.
+    Add(String, String),
+    /// Yells what the first monkey yells minus what the second one does.
+    Sub(String, String),
+    /// Yells the product of what two monkeys yell.
+    Times(String, String),
+    /// Yells what the first monkey yells divided by what the second one does.
+    Div(String, String),
+}
+
+/// Parses the job of each monkey, by name.
+pub fn parse(input: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    let p = Parser::new(input);
+    input
+        .lines()
+        .map(|l| {
+            let (name, job) = p.split_once(l, ": ")?;
+            let monkey = if let Ok(num) = job.parse::<i64>() {
+                Monkey::Number(num)
+            } else {
+                let (first, rest) = p.split_once(job, " ")?;
+                let (operator, second) = p.split_once(rest, " ")?;
+                match operator {
+                    "+" => Monkey::Add(first.to_string(), second.to_string()),
+                    "-" => Monkey::Sub(first.to_string(), second.to_string()),
+                    "*" => Monkey::Times(first.to_string(), second.to_string()),
+                    "/" => Monkey::Div(first.to_string(), second.to_string()),
+                    a => return Err(p.error(a, "one of +, -, *, /")),
+                }
+            };
+            Ok((name.to_string(), monkey))
+        })
+        .collect()
+}
+
+fn solve_rec(curr: &str, map: &HashMap<String, Monkey>) -> i64 {
+    let monkey = map.get(curr).unwrap();
+    match monkey {
+        Monkey::Number(num) => *num,
+        Monkey::Add(a, b) => solve_rec(a, map) + solve_rec(b, map),
+        Monkey::Sub(a, b) => solve_rec(a, map) - solve_rec(b, map),
+        Monkey::Times(a, b) => solve_rec(a, map) * solve_rec(b, map),
+        Monkey::Div(a, b) => solve_rec(a, map) / solve_rec(b, map),
+    }
+}
+
+/// What the monkey named `root` yells.
+pub fn solve_part_one(input: HashMap<String, Monkey>) -> Answer {
+    let val = solve_rec("root", &input);
+
+    val.into()
+}
+
+fn solve_rec_2(curr: &str, my_val: i64, map: &HashMap<String, Monkey>) -> i64 {
+    if curr == "humn" {
+        return my_val;
+    }
+
+    let monkey = map.get(curr).unwrap();
+    match monkey {
+        Monkey::Number(num) => *num,
+        Monkey::Add(a, b) => solve_rec_2(a, my_val, map) + solve_rec_2(b, my_val, map),
+        Monkey::Sub(a, b) => solve_rec_2(a, my_val, map) - solve_rec_2(b, my_val, map),
+        Monkey::Times(a, b) => solve_rec_2(a, my_val, map) * solve_rec_2(b, my_val, map),
+        Monkey::Div(a, b) => solve_rec_2(a, my_val, map) / solve_rec_2(b, my_val, map),
+    }
+}
+
+fn find_matching(start: &str, other_val: i64, map: &HashMap<String, Monkey>) -> i64 {
+    for my_val in 0..i64::MAX {
+        let val_a = solve_rec_2(start, my_val, map);
+        if val_a == other_val {
+            return my_val;
+        }
+    }
+
+    panic!("Failed to find matching value :(");
+}
+
+fn depends_on_humn(curr: &str, map: &HashMap<String, Monkey>) -> bool {
+    if curr == "humn" {
+        return true;
+    }
+
+    match map.get(curr).unwrap() {
+        Monkey::Number(_) => false,
+        Monkey::Add(a, b) | Monkey::Sub(a, b) | Monkey::Times(a, b) | Monkey::Div(a, b) => {
+            depends_on_humn(a, map) || depends_on_humn(b, map)
+        }
+    }
+}
+
+/// The number `humn` has to yell for `curr` to yell `wanted`, working down from `curr` by undoing
+/// each job on the way to `humn`. Only one side of each job depends on `humn`.
+fn solve_for_humn(curr: &str, wanted: i64, map: &HashMap<String, Monkey>) -> i64 {
+    if curr == "humn" {
+        return wanted;
+    }
+
+    let (a, b) = match map.get(curr).unwrap() {
+        Monkey::Number(_) => panic!("{curr} does not depend on humn"),
+        Monkey::Add(a, b) | Monkey::Sub(a, b) | Monkey::Times(a, b) | Monkey::Div(a, b) => (a, b),
+    };
+    let humn_in_a = depends_on_humn(a, map);
+    let other = if humn_in_a {
+        solve_rec(b, map)
+    } else {
+        solve_rec(a, map)
+    };
+
+    let (next, wanted) = match (map.get(curr).unwrap(), humn_in_a) {
+        (Monkey::Add(..), true) => (a, wanted - other),
+        (Monkey::Add(..), false) => (b, wanted - other),
+        (Monkey::Sub(..), true) => (a, wanted + other),
+        (Monkey::Sub(..), false) => (b, other - wanted),
+        (Monkey::Times(..), true) => (a, wanted / other),
+        (Monkey::Times(..), false) => (b, wanted / other),
+        (Monkey::Div(..), true) => (a, wanted * other),
+        (Monkey::Div(..), false) => (b, other / wanted),
+        (Monkey::Number(_), _) => unreachable!(),
+    };
+    solve_for_humn(next, wanted, map)
+}
+
+/// What `humn` has to yell for the two monkeys `root` waits for to yell the same number.
+pub fn solve_part_two(input: HashMap<String, Monkey>) -> Answer {
+    let val = if let Monkey::Add(a, b) = input.get("root").unwrap() {
+        // Either side may listen to humn, the other one yells the number to match
+        let (humn_side, other_side) = if depends_on_humn(a, &input) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let wanted = solve_rec(other_side, &input);
+        solve_for_humn(humn_side, wanted, &input)
+    } else {
+        panic!("Root was not add?! {:?}", input.get("root").unwrap())
+    };
+
+    val.into()
+}
+
+/// Part 2 by trying every number for `humn` from 0 up, for checking [`solve_part_two`] against.
+pub fn reference_part_two(input: HashMap<String, Monkey>) -> Answer {
+    let val = if let Monkey::Add(a, b) = input.get("root").unwrap() {
+        let val_b = solve_rec_2(b, 0, &input);
+        find_matching(a, val_b, &input)
+    } else {
+        panic!("Root was not add?! {:?}", input.get("root").unwrap())
+    };
+
+    val.into()
+}
@@ -0,0 +1,143 @@
+//! The answer to a part, as returned by the solutions.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ocr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Letters drawn on a screen, see [`ocr`] for reading them.
+    Pixels(PixelGrid),
+}
+
+impl Answer {
+    /// What to submit to the site, letters on a screen are read with [`ocr::read`].
+    pub fn submission(&self) -> Result<String, ocr::Error> {
+        match self {
+            Answer::Integer(n) => Ok(n.to_string()),
+            Answer::Text(text) => Ok(text.clone()),
+            Answer::Pixels(screen) => ocr::read(screen),
+        }
+    }
+
+    /// Whether the answer matches a known answer, written the way it is submitted.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Answer::Integer(n) => expected.parse::<i128>() == Ok(*n),
+            Answer::Text(text) => text == expected,
+            Answer::Pixels(screen) => ocr::read(screen).is_ok_and(|letters| letters == expected),
+        }
+    }
+
+    /// The answer on a single line, for tables. A screen is shown as the letters it reads.
+    pub fn one_line(&self) -> String {
+        match self {
+            Answer::Pixels(screen) => {
+                ocr::read(screen).unwrap_or_else(|_| "unreadable letters".to_string())
+            }
+            answer => answer.to_string(),
+        }
+    }
+
+    /// Whether the answer spans several lines when displayed.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Pixels(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Pixels(grid) => write!(f, "{grid}"),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<PixelGrid> for Answer {
+    fn from(grid: PixelGrid) -> Self {
+        Answer::Pixels(grid)
+    }
+}
+
+/// A screen of pixels that are either lit or dark, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PixelGrid {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl PixelGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        PixelGrid {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        self.pixels[y * self.width + x] = lit;
+    }
+}
+
+/// Lit pixels are drawn as `#` and dark ones as `.`, one line per row.
+impl Display for PixelGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
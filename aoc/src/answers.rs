@@ -0,0 +1,271 @@
+//! Known answers for a day's inputs, kept in `answers.txt` in the day directory with one
+//! `<input file> <part> <answer>` line per answer. Lines starting with `#` are comments.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{parse::ParseError, solution::Error, Answer, Day, Part};
+
+/// The inputs that are checked for every day, if they exist.
+pub const INPUT_FILES: [&str; 2] = ["test.txt", "input.txt"];
+
+/// The directory of a day with more inputs, such as those of teammates, as `<name>.txt`. Their
+/// answers are kept in `answers.txt` like any other, e.g. `inputs/alice.txt 1 24000`.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// An answer that is known to be correct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Known {
+    pub file: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    answers: Vec<Known>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Answers::parse(&content)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let answers = content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .filter_map(|l| {
+                let (file, rest) = l.split_once(' ')?;
+                let (part, answer) = rest.trim_start().split_once(' ')?;
+                let part = match part {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return None,
+                };
+
+                Some(Known {
+                    file: file.to_string(),
+                    part,
+                    answer: answer.trim().to_string(),
+                })
+            })
+            .collect();
+
+        Answers { answers }
+    }
+
+    pub fn get(&self, file: &str, part: Part) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.file == file && a.part == part)
+            .map(|a| a.answer.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Known> {
+        self.answers.iter()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        expected: String,
+        actual: Answer,
+    },
+    /// The part is not implemented yet.
+    Todo,
+    Panicked(String),
+    /// The input could not be parsed.
+    Invalid(ParseError),
+    /// There is no known answer to compare with.
+    Unknown,
+    /// The input could not be read.
+    NoInput(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Wrong { .. }
+                | Outcome::Panicked(_)
+                | Outcome::Invalid(_)
+                | Outcome::NoInput(_)
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Wrong { expected, actual } => {
+                write!(f, "FAILED, expected {expected} but got {actual}")
+            }
+            Outcome::Todo => write!(f, "skipped, not implemented"),
+            Outcome::Panicked(message) => write!(f, "FAILED, panicked: {message}"),
+            Outcome::Invalid(err) => write!(f, "FAILED, {err}"),
+            Outcome::Unknown => write!(f, "skipped, no known answer"),
+            Outcome::NoInput(err) => write!(f, "FAILED, no input: {err}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub file: PathBuf,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+/// Runs both parts of a day against every input that has a known answer.
+///
+/// `dir` is the day's directory, containing its inputs and `answers.txt`. An input that can't be
+/// read fails both parts with [`Outcome::NoInput`].
+pub fn check_day(day: &Day, dir: &Path) -> io::Result<Vec<Check>> {
+    let answers = Answers::load(&dir.join("answers.txt"))?;
+
+    let mut checks = Vec::new();
+    for file in input_files(dir, &answers)? {
+        let path = dir.join(&file);
+        let input = fs::read_to_string(&path).map_err(|err| err.to_string());
+
+        for part in [Part::One, Part::Two] {
+            let outcome = match (&input, answers.get(&file, part)) {
+                (Err(err), _) => Outcome::NoInput(err.clone()),
+                (Ok(input), Some(expected)) => match check(day, part, input, expected) {
+                    Outcome::Invalid(err) => Outcome::Invalid(err.in_file(&path)),
+                    outcome => outcome,
+                },
+                (Ok(_), None) => Outcome::Unknown,
+            };
+
+            checks.push(Check {
+                file: path.clone(),
+                part,
+                outcome,
+            });
+        }
+    }
+
+    Ok(checks)
+}
+
+/// How both parts of a day did on one of its inputs, see [`check_inputs`].
+#[derive(Debug, Clone)]
+pub struct InputCheck {
+    /// The input, relative to the day's directory.
+    pub file: String,
+    pub outcomes: [Outcome; 2],
+}
+
+impl InputCheck {
+    pub fn is_failure(&self) -> bool {
+        self.outcomes.iter().any(Outcome::is_failure)
+    }
+
+    /// Whether a part was checked and none failed.
+    pub fn passes(&self) -> bool {
+        !self.is_failure() && self.outcomes.contains(&Outcome::Correct)
+    }
+}
+
+/// The input and whether it passes, followed by the outcome of each part on a line of its own.
+impl Display for InputCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = if self.is_failure() {
+            "FAILED"
+        } else if self.passes() {
+            "passed"
+        } else {
+            "not checked"
+        };
+        writeln!(f, "{}: {verdict}", self.file)?;
+        for (part, outcome) in [Part::One, Part::Two].into_iter().zip(&self.outcomes) {
+            writeln!(f, "  {part}: {outcome}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Like [`check_day`], but with the outcomes of both parts together for each input.
+pub fn check_inputs(day: &Day, dir: &Path) -> io::Result<Vec<InputCheck>> {
+    let checks = check_day(day, dir)?;
+    Ok(checks
+        .chunks(2)
+        .map(|parts| InputCheck {
+            file: relative(&parts[0].file, dir),
+            outcomes: [parts[0].outcome.clone(), parts[1].outcome.clone()],
+        })
+        .collect())
+}
+
+fn relative(path: &Path, dir: &Path) -> String {
+    path.strip_prefix(dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// The inputs of a day in `dir`: those of [`INPUT_FILES`] that exist, the ones in
+/// [`INPUTS_DIR`] by name, and then any other file there is a known answer for.
+pub fn input_files(dir: &Path, answers: &Answers) -> io::Result<Vec<String>> {
+    let mut files: Vec<String> = INPUT_FILES
+        .into_iter()
+        .filter(|f| dir.join(f).exists())
+        .map(str::to_string)
+        .collect();
+
+    let mut named = Vec::new();
+    match fs::read_dir(dir.join(INPUTS_DIR)) {
+        Ok(entries) => {
+            for entry in entries {
+                let name = entry?.file_name().to_string_lossy().into_owned();
+                if name.ends_with(".txt") {
+                    named.push(format!("{INPUTS_DIR}/{name}"));
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+    named.sort();
+    files.extend(named);
+
+    for answer in answers.iter() {
+        if !files.contains(&answer.file) {
+            files.push(answer.file.clone());
+        }
+    }
+    Ok(files)
+}
+
+pub fn check(day: &Day, part: Part, input: &str, expected: &str) -> Outcome {
+    outcome(day.try_solve(part, input), Some(expected))
+}
+
+/// How the result of solving a part compares to its known answer, for callers that solve the
+/// part themselves. A part without a known answer is [`Outcome::Unknown`] unless it failed.
+pub fn outcome(result: Result<Answer, Error>, expected: Option<&str>) -> Outcome {
+    match (result, expected) {
+        (Ok(actual), Some(expected)) if actual.matches(expected) => Outcome::Correct,
+        (Ok(actual), Some(expected)) => Outcome::Wrong {
+            expected: expected.to_string(),
+            actual,
+        },
+        (Ok(_), None) => Outcome::Unknown,
+        (Err(Error::Parse(err)), _) => Outcome::Invalid(err),
+        (Err(Error::Panic(panic)), _) if panic.is_todo() => Outcome::Todo,
+        (Err(Error::Panic(panic)), _) => Outcome::Panicked(panic.message),
+    }
+}
@@ -0,0 +1,121 @@
+//! Command line arguments for running a day, shared by `aoc run` and the per-day binaries.
+
+use std::{env, fmt::Display, path::PathBuf};
+
+use clap::{Args, Parser};
+
+use crate::Part;
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct RunArgs {
+    /// Which part to run, 1 or 2; both parts are run if left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// Input files to use instead of the day's input.txt, `-` reads from stdin
+    #[arg(short, long = "input", num_args = 1..)]
+    pub inputs: Vec<PathBuf>,
+    /// Only print the answers, one per line
+    #[arg(short, long)]
+    pub quiet: bool,
+    /// Solve while reading the input instead of reading it into memory first, for days that
+    /// support it
+    #[arg(long)]
+    pub stream: bool,
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(_) => vec![Part::Two],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+/// Arguments of the per-day binaries.
+#[derive(Parser, Debug)]
+#[command(about = "Run a day of advent of code")]
+pub struct DayCli {
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+/// The environment variables the per-day binaries have always been configured with.
+pub const PART_VAR: &str = "part";
+pub const TEST_FILE_VAR: &str = "test_file";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    InvalidPart(String),
+}
+
+impl Display for EnvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvError::InvalidPart(value) => write!(
+                f,
+                "invalid value '{value}' for the {PART_VAR} environment variable, expected part1 or part2"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EnvError {}
+
+/// Fills in what was not given on the command line from the `part` and `test_file`
+/// environment variables.
+pub fn apply_env(
+    args: &mut RunArgs,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<(), EnvError> {
+    for (name, value) in vars {
+        if name == PART_VAR && args.part.is_none() {
+            args.part = Some(match value.as_str() {
+                "part1" | "1" => 1,
+                "part2" | "2" => 2,
+                _ => return Err(EnvError::InvalidPart(value)),
+            });
+        } else if name == TEST_FILE_VAR && args.inputs.is_empty() {
+            args.inputs.push(PathBuf::from(value));
+        }
+    }
+
+    Ok(())
+}
+
+/// Environment variables that look like a misspelling of `part` or `test_file`, along with the
+/// name they were probably meant to be.
+pub fn misspelled_vars(names: impl IntoIterator<Item = String>) -> Vec<(String, &'static str)> {
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let normalized = name.to_lowercase().replace(['-', '_', ' '], "");
+            let intended = match normalized.as_str() {
+                "part" | "parts" => PART_VAR,
+                "testfile" | "testfiles" | "testinput" | "inputfile" => TEST_FILE_VAR,
+                _ => return None,
+            };
+            (name != intended).then_some((name, intended))
+        })
+        .collect()
+}
+
+/// Parses the arguments of a per-day binary, taking the environment variables into account.
+///
+/// Environment variables that look misspelled are warned about on stderr.
+pub fn day_args() -> Result<RunArgs, EnvError> {
+    let mut args = DayCli::parse().run;
+
+    // Variables that are not valid unicode can't be any of ours
+    let vars: Vec<(String, String)> = env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+        .collect();
+
+    for (name, intended) in misspelled_vars(vars.iter().map(|(name, _)| name.clone())) {
+        eprintln!("warning: ignoring the environment variable {name}, did you mean {intended}?");
+    }
+
+    apply_env(&mut args, vars)?;
+    Ok(args)
+}
@@ -0,0 +1,215 @@
+//! Times parsing and both parts of a day separately.
+//!
+//! Every sample of a part parses the input again since the part consumes it, but only the time
+//! spent in the part is counted. Days that parse lazily into an iterator do most of their
+//! parsing inside the parts.
+
+use std::{
+    fmt::Display,
+    hint::black_box,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::ParseError, solution, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Runs before measuring that are not counted.
+    pub warmup: usize,
+    /// How many samples to take at most.
+    pub samples: usize,
+    /// Stop taking more samples of a measurement once this much time has been spent on it,
+    /// there is always at least one sample.
+    pub max_time: Duration,
+    /// Give up on a measurement if its first run takes longer than this.
+    pub timeout: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 1,
+            samples: 10,
+            max_time: Duration::from_secs(5),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let total: Duration = samples.iter().sum();
+
+        Stats {
+            samples: samples.len(),
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: samples[samples.len() / 2].as_nanos() as u64,
+            mean_ns: (total / samples.len() as u32).as_nanos() as u64,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Measurement {
+    Sampled(Stats),
+    /// The first run panicked, e.g. because the part is not implemented.
+    Panicked,
+    /// The first run did not finish within [`Options::timeout`].
+    TimedOut,
+}
+
+impl Measurement {
+    pub fn stats(&self) -> Option<&Stats> {
+        match self {
+            Measurement::Sampled(stats) => Some(stats),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Measurement,
+    pub part_one: Measurement,
+    pub part_two: Measurement,
+}
+
+/// Fails if the input cannot be parsed, nothing is measured in that case. Preparing the input
+/// (see [`Solution::SHAPE`]) is not part of the measurements.
+pub fn bench<S: Solution + 'static>(input: &str, options: &Options) -> Result<Timings, ParseError> {
+    let input = solution::prepare::<S>(input)?;
+    S::parse(&input)?;
+    let input: Arc<str> = Arc::from(&*input);
+
+    let parse = measure(options, {
+        let input = input.clone();
+        move || {
+            let start = Instant::now();
+            let parsed = black_box(S::parse(black_box(&input)).expect("parsed before"));
+            let elapsed = start.elapsed();
+            drop(parsed);
+            elapsed
+        }
+    });
+
+    let part_one = measure(options, {
+        let input = input.clone();
+        move || {
+            let parsed = S::parse(&input).expect("parsed before");
+            let start = Instant::now();
+            black_box(S::part_one(black_box(parsed)));
+            start.elapsed()
+        }
+    });
+
+    let part_two = measure(options, move || {
+        let parsed = S::parse(&input).expect("parsed before");
+        let start = Instant::now();
+        black_box(S::part_two(black_box(parsed)));
+        start.elapsed()
+    });
+
+    Ok(Timings {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// Takes samples of `run`, which returns the time that should be counted for that run.
+///
+/// The measurement runs on its own thread so that it can be abandoned when the first run times
+/// out, that thread is left running in the background.
+fn measure(options: &Options, mut run: impl FnMut() -> Duration + Send + 'static) -> Measurement {
+    let (sender, receiver) = mpsc::channel();
+    let timeout = options.timeout;
+    let options = options.clone();
+
+    // Sends `None` once the first run is done and the stats when all samples have been taken,
+    // a panic drops the sender instead
+    thread::spawn(move || {
+        let first = run();
+        let _ = sender.send(None);
+
+        for _ in 1..options.warmup {
+            run();
+        }
+
+        let mut samples = Vec::with_capacity(options.samples);
+        if options.warmup == 0 {
+            samples.push(first);
+        }
+
+        let start = Instant::now();
+        while samples.len() < options.samples.max(1)
+            && (samples.is_empty() || start.elapsed() < options.max_time)
+        {
+            samples.push(run());
+        }
+
+        let _ = sender.send(Some(Stats::from_samples(samples)));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(_) => {}
+        Err(RecvTimeoutError::Timeout) => return Measurement::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => return Measurement::Panicked,
+    }
+
+    match receiver.recv() {
+        Ok(Some(stats)) => Measurement::Sampled(stats),
+        _ => Measurement::Panicked,
+    }
+}
+
+/// Formats a duration with three significant digits, e.g. `12.3µs`.
+pub struct Short(pub Duration);
+
+impl Display for Short {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let (value, unit) = if nanos < 1e3 {
+            (nanos, "ns")
+        } else if nanos < 1e6 {
+            (nanos / 1e3, "µs")
+        } else if nanos < 1e9 {
+            (nanos / 1e6, "ms")
+        } else {
+            (nanos / 1e9, "s")
+        };
+
+        let precision = if value < 10.0 {
+            2
+        } else if value < 100.0 {
+            1
+        } else {
+            0
+        };
+        f.pad(&format!("{value:.precision$}{unit}"))
+    }
+}
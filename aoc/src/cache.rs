@@ -0,0 +1,58 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// On-disk cache for anything fetched from the advent of code site, with a directory per year
+/// and one per day in it.
+///
+/// The location is taken from `AOC_CACHE_DIR`, falling back to `$XDG_CACHE_HOME/aoc` and then
+/// `~/.cache/aoc`.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Cache { root: root.into() }
+    }
+
+    pub fn from_env() -> Self {
+        let root = if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+            PathBuf::from(dir)
+        } else if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+            PathBuf::from(dir).join("aoc")
+        } else if let Some(home) = env::var_os("HOME") {
+            PathBuf::from(home).join(".cache").join("aoc")
+        } else {
+            PathBuf::from(".aoc-cache")
+        };
+
+        Cache::new(root)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("{day:02}"))
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input.txt")
+    }
+
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join(crate::puzzle::PAGE_FILE)
+    }
+
+    pub fn leaderboard_path(&self, year: u16, id: u64) -> PathBuf {
+        self.year_dir(year).join(format!("leaderboard-{id}.json"))
+    }
+}
@@ -0,0 +1,265 @@
+use std::{env, fmt::Display, fs, io, sync::Arc};
+
+use reqwest::{
+    blocking::{self, Response},
+    cookie::Jar,
+    StatusCode, Url,
+};
+
+use crate::{cache::Cache, leaderboard::REFRESH_AFTER, ledger::Verdict, puzzle::Page, Part};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/ViddeM/advent-of-code-2022";
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession,
+    ExpiredSession,
+    NotAvailable {
+        year: u16,
+        day: u8,
+    },
+    /// The site answered with a page instead of the leaderboard's JSON.
+    NoLeaderboard(u64),
+    InvalidBaseUrl(String),
+    Status(StatusCode),
+    UnknownResponse(String),
+    Http(reqwest::Error),
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie, set AOC_SESSION to the value of the `session` cookie from adventofcode.com"
+            ),
+            Error::ExpiredSession => write!(
+                f,
+                "the session cookie was rejected, it has probably expired; log in again and update AOC_SESSION"
+            ),
+            Error::NotAvailable { year, day } => {
+                write!(f, "{year} day {day} is not available (yet)")
+            }
+            Error::NoLeaderboard(id) => write!(
+                f,
+                "can't see private leaderboard {id}, check that you are a member and that AOC_SESSION has not expired"
+            ),
+            Error::InvalidBaseUrl(url) => write!(f, "invalid base url '{url}'"),
+            Error::Status(status) => write!(f, "unexpected response from server: {status}"),
+            Error::UnknownResponse(body) => write!(f, "unrecognized response from server: {body}"),
+            Error::Http(err) => write!(f, "request failed: {err}"),
+            Error::Io(err) => write!(f, "failed to write to the cache: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Client for the advent of code site, authenticated with a session cookie.
+pub struct Client {
+    base_url: Url,
+    http: blocking::Client,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Result<Self, Error> {
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(Error::MissingSession);
+        }
+
+        // Without a trailing slash joining paths would replace the last segment of the base
+        let mut base_url: Url = base_url
+            .parse()
+            .map_err(|_| Error::InvalidBaseUrl(base_url.to_string()))?;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+
+        let jar = Jar::default();
+        jar.add_cookie_str(&format!("session={session}"), &base_url);
+
+        let http = blocking::Client::builder()
+            .cookie_provider(Arc::new(jar))
+            .user_agent(USER_AGENT)
+            .build()?;
+
+        Ok(Client { base_url, http })
+    }
+
+    /// Creates a client using the session cookie in `AOC_SESSION` and, if set, the site in
+    /// `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = env::var("AOC_SESSION").unwrap_or_default();
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Client::new(&base_url, &session)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let resp = self.get(&format!("{year}/day/{day}/input"))?;
+        match resp.status() {
+            status if status.is_success() => Ok(resp.text()?),
+            // The site answers with 400 when the cookie is missing or no longer valid
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                Err(Error::ExpiredSession)
+            }
+            StatusCode::NOT_FOUND => Err(Error::NotAvailable { year, day }),
+            status => Err(Error::Status(status)),
+        }
+    }
+
+    /// Returns the input from the cache, only downloading it if it has not been cached before.
+    pub fn cached_input(&self, cache: &Cache, year: u16, day: u8) -> Result<String, Error> {
+        let path = cache.input_path(year, day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        let input = self.input(year, day)?;
+        fs::create_dir_all(cache.day_dir(year, day))?;
+        fs::write(&path, &input)?;
+
+        Ok(input)
+    }
+
+    /// The page describing a puzzle, which includes part 2 once part 1 has been solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        let resp = self.get(&format!("{year}/day/{day}"))?;
+        match resp.status() {
+            status if status.is_success() => Ok(resp.text()?),
+            StatusCode::NOT_FOUND => Err(Error::NotAvailable { year, day }),
+            status => Err(Error::Status(status)),
+        }
+    }
+
+    /// Returns the puzzle page from the cache, downloading it again unless the cached page
+    /// already has both parts.
+    pub fn cached_puzzle(&self, cache: &Cache, year: u16, day: u8) -> Result<String, Error> {
+        let path = cache.puzzle_path(year, day);
+        if let Ok(page) = fs::read_to_string(&path) {
+            if Page::parse(&page).is_complete() {
+                return Ok(page);
+            }
+        }
+
+        let page = self.puzzle(year, day)?;
+        fs::create_dir_all(cache.day_dir(year, day))?;
+        fs::write(&path, &page)?;
+
+        Ok(page)
+    }
+
+    /// The JSON of a private leaderboard, which only its members can see.
+    pub fn leaderboard(&self, year: u16, id: u64) -> Result<String, Error> {
+        let resp = self.get(&format!("{year}/leaderboard/private/view/{id}.json"))?;
+        match resp.status() {
+            status if status.is_success() => {
+                // Anyone else is redirected to a page
+                let body = resp.text()?;
+                if body.trim_start().starts_with('{') {
+                    Ok(body)
+                } else {
+                    Err(Error::NoLeaderboard(id))
+                }
+            }
+            StatusCode::NOT_FOUND => Err(Error::NoLeaderboard(id)),
+            status => Err(Error::Status(status)),
+        }
+    }
+
+    /// Returns the leaderboard from the cache, downloading it again once the cached copy is
+    /// older than [`REFRESH_AFTER`](crate::leaderboard::REFRESH_AFTER), as the site asks.
+    pub fn cached_leaderboard(&self, cache: &Cache, year: u16, id: u64) -> Result<String, Error> {
+        let path = cache.leaderboard_path(year, id);
+        let fresh = fs::metadata(&path)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age.as_secs() < REFRESH_AFTER);
+        if fresh {
+            if let Ok(json) = fs::read_to_string(&path) {
+                return Ok(json);
+            }
+        }
+
+        let json = self.leaderboard(year, id)?;
+        fs::create_dir_all(cache.year_dir(year))?;
+        fs::write(&path, &json)?;
+
+        Ok(json)
+    }
+
+    /// Submits an answer. Whether the guess should be sent at all is up to the caller, see
+    /// [`Ledger::check`](crate::ledger::Ledger::check).
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, Error> {
+        let level = part.number().to_string();
+        let resp = self
+            .http
+            .post(self.url(&format!("{year}/day/{day}/answer"))?)
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()?;
+
+        match resp.status() {
+            status if status.is_success() => {
+                let body = resp.text()?;
+                parse_verdict(&body).ok_or(Error::UnknownResponse(body))
+            }
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                Err(Error::ExpiredSession)
+            }
+            StatusCode::NOT_FOUND => Err(Error::NotAvailable { year, day }),
+            status => Err(Error::Status(status)),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<Response, Error> {
+        Ok(self.http.get(self.url(path)?).send()?)
+    }
+
+    fn url(&self, path: &str) -> Result<Url, Error> {
+        self.base_url
+            .join(path)
+            .map_err(|_| Error::InvalidBaseUrl(self.base_url.to_string()))
+    }
+}
+
+/// Reads the verdict from the page the site responds with after submitting an answer.
+pub fn parse_verdict(body: &str) -> Option<Verdict> {
+    if body.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        Some(if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Some(Verdict::RateLimited { wait })
+    } else if body.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,47 @@
+//! The registry of every solved day, with a module per year. Each day's `solution.rs` is
+//! compiled as a module of the matching file in its year, which implements
+//! [`Solution`](crate::Solution) for it.
+
+use crate::solution::Day;
+
+pub mod year2021;
+pub mod year2022;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<year2021::Day01>(),
+    Day::of::<year2021::Day03>(),
+    Day::streaming::<year2022::Day01>(),
+    Day::streaming::<year2022::Day02>(),
+    Day::with_reference::<year2022::Day03>(),
+    Day::streaming::<year2022::Day04>(),
+    Day::of::<year2022::Day05>(),
+    Day::streaming::<year2022::Day06>(),
+    Day::of::<year2022::Day07>(),
+    Day::of::<year2022::Day08>(),
+    Day::of::<year2022::Day09>(),
+    Day::streaming::<year2022::Day10>(),
+    Day::of::<year2022::Day11>(),
+    Day::of::<year2022::Day12>(),
+    Day::of::<year2022::Day13>(),
+    Day::of::<year2022::Day14>(),
+    Day::with_reference::<year2022::Day15>(),
+    Day::of::<year2022::Day16>(),
+    Day::with_reference::<year2022::Day21>(),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The years with solved days, in order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|d| d.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+/// The solved days of a year, in order.
+pub fn in_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
@@ -0,0 +1,27 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2021/day01/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Shape, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = solution::Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
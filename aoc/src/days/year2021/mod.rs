@@ -0,0 +1,7 @@
+//! The days of 2021.
+
+mod day01;
+mod day03;
+
+pub use day01::Day01;
+pub use day03::Day03;
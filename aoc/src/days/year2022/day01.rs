@@ -0,0 +1,43 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day01/src/solution.rs"]
+mod solution;
+
+use std::io::BufRead;
+
+use crate::{
+    parse::ParseError,
+    stream::{self, Records},
+    Answer, Shape, Solution, Streaming,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const SHAPE: Shape = Shape::Blocks { lines: None };
+
+    type Parsed<'a> = Vec<Option<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input.into_iter())
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input.into_iter())
+    }
+}
+
+impl Streaming for Day01 {
+    fn stream_part_one<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Records::new(input, solution::parse_line).solve(|records| solution::solve_part_one(records))
+    }
+
+    fn stream_part_two<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Records::new(input, solution::parse_line).solve(|records| solution::solve_part_two(records))
+    }
+}
@@ -0,0 +1,43 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day02/src/solution.rs"]
+mod solution;
+
+use std::io::BufRead;
+
+use crate::{
+    parse::ParseError,
+    stream::{self, Records},
+    Answer, Shape, Solution, Streaming,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = Box<dyn Iterator<Item = (u8, u8)> + 'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Box::new(solution::parse(input)?.into_iter()))
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
+
+impl Streaming for Day02 {
+    fn stream_part_one<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Records::new(input, solution::parse_line).solve(|records| solution::solve_part_one(records))
+    }
+
+    fn stream_part_two<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Records::new(input, solution::parse_line).solve(|records| solution::solve_part_two(records))
+    }
+}
@@ -0,0 +1,37 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day03/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Reference, Shape, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = Box<dyn Iterator<Item = &'a str> + 'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Box::new(solution::parse(input)?.into_iter()))
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
+
+impl Reference for Day03 {
+    fn reference_part_one(input: Self::Parsed<'_>) -> Option<Answer> {
+        Some(solution::reference_part_one(input))
+    }
+
+    fn reference_part_two(input: Self::Parsed<'_>) -> Option<Answer> {
+        Some(solution::reference_part_two(input))
+    }
+}
@@ -0,0 +1,43 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day04/src/solution.rs"]
+mod solution;
+
+use std::io::BufRead;
+
+use crate::{
+    parse::ParseError,
+    stream::{self, Records},
+    Answer, Shape, Solution, Streaming,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = Box<dyn Iterator<Item = ((u32, u32), (u32, u32))> + 'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Box::new(solution::parse(input)?.into_iter()))
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
+
+impl Streaming for Day04 {
+    fn stream_part_one<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Records::new(input, solution::parse_line).solve(|records| solution::solve_part_one(records))
+    }
+
+    fn stream_part_two<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Records::new(input, solution::parse_line).solve(|records| solution::solve_part_two(records))
+    }
+}
@@ -0,0 +1,33 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day05/src/solution.rs"]
+mod solution;
+
+use std::collections::HashMap;
+
+use crate::{parse::ParseError, Answer, Shape, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const SHAPE: Shape = Shape::Blocks { lines: None };
+
+    type Parsed<'a> = (
+        HashMap<u8, Vec<char>>,
+        Box<dyn Iterator<Item = (u32, u8, u8)> + 'a>,
+    );
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (stacks, instructions) = solution::parse(input)?;
+        Ok((stacks, Box::new(instructions.into_iter())))
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
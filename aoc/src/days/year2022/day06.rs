@@ -0,0 +1,45 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day06/src/solution.rs"]
+mod solution;
+
+use std::io::BufRead;
+
+use crate::{
+    parse::ParseError,
+    stream::{self, Bytes},
+    Answer, Shape, Solution, Streaming,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = &'a [u8];
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
+
+impl Streaming for Day06 {
+    fn stream_part_one<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Bytes::new(input, |c| c.is_ascii_lowercase(), "a lowercase letter")
+            .solve(|bytes| solution::find_packets_streaming(bytes, 4).into())
+    }
+
+    fn stream_part_two<R: BufRead>(input: R) -> Result<Answer, stream::Error> {
+        Bytes::new(input, |c| c.is_ascii_lowercase(), "a lowercase letter")
+            .solve(|bytes| solution::find_packets_streaming(bytes, 14).into())
+    }
+}
@@ -0,0 +1,27 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day07/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Shape, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = Box<dyn Iterator<Item = solution::Input> + 'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Box::new(solution::parse(input)?.into_iter()))
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
@@ -0,0 +1,27 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day08/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Shape, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+    const SHAPE: Shape = Shape::Grid;
+
+    type Parsed<'a> = aoc_utils::Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
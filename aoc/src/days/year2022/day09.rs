@@ -0,0 +1,27 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day09/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Shape, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = Box<dyn Iterator<Item = solution::Instruction> + 'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Box::new(solution::parse(input)?.into_iter()))
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
@@ -0,0 +1,33 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day15/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Reference, Shape, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = solution::Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
+
+impl Reference for Day15 {
+    fn reference_part_one(input: Self::Parsed<'_>) -> Option<Answer> {
+        Some(solution::reference_part_one(input))
+    }
+}
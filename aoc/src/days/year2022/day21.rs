@@ -0,0 +1,35 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../2022/day21/src/solution.rs"]
+mod solution;
+
+use std::collections::HashMap;
+
+use crate::{parse::ParseError, Answer, Reference, Shape, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 21;
+    const SHAPE: Shape = Shape::Lines;
+
+    type Parsed<'a> = HashMap<String, solution::Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
+
+impl Reference for Day21 {
+    fn reference_part_two(input: Self::Parsed<'_>) -> Option<Answer> {
+        Some(solution::reference_part_two(input))
+    }
+}
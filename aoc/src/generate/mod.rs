@@ -0,0 +1,89 @@
+//! Random but well-formed inputs for the solved days, as the real inputs can't be shared. They
+//! are for stress tests, benchmarks on inputs of any size and property tests.
+//!
+//! Each day has a module in the module of its year with a `generate` function, which writes an
+//! input of about `size` records, at least one, where what a record is depends on the day.
+//! Inputs follow the rules the puzzle states and the ones the solutions rely on, so that both
+//! parts can be solved.
+//! The same seed and size always give the same input.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub mod year2021;
+pub mod year2022;
+
+type GenerateFn = fn(&mut Rng, usize, &mut dyn Write) -> io::Result<()>;
+
+/// Generates inputs for a day, as listed in [`GENERATORS`].
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// A size that gives an input about as large as a real one.
+    pub size: usize,
+    generate: GenerateFn,
+}
+
+impl Generator {
+    pub const fn new(year: u16, day: u8, size: usize, generate: GenerateFn) -> Self {
+        Generator {
+            year,
+            day,
+            size,
+            generate,
+        }
+    }
+
+    pub fn write(&self, seed: u64, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        (self.generate)(&mut Rng::with_seed(seed), size.max(1), out)
+    }
+
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let mut input = Vec::new();
+        self.write(seed, size, &mut input)
+            .expect("writing to a vector does not fail");
+        String::from_utf8(input).expect("inputs are text")
+    }
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator::new(2021, 1, 2000, year2021::day01::generate),
+    Generator::new(2021, 3, 1000, year2021::day03::generate),
+    Generator::new(2022, 1, 250, year2022::day01::generate),
+    Generator::new(2022, 2, 2500, year2022::day02::generate),
+    Generator::new(2022, 3, 100, year2022::day03::generate),
+    Generator::new(2022, 4, 1000, year2022::day04::generate),
+    Generator::new(2022, 5, 500, year2022::day05::generate),
+    Generator::new(2022, 6, 4096, year2022::day06::generate),
+    Generator::new(2022, 7, 300, year2022::day07::generate),
+    Generator::new(2022, 8, 99, year2022::day08::generate),
+    Generator::new(2022, 9, 2000, year2022::day09::generate),
+    Generator::new(2022, 10, 140, year2022::day10::generate),
+    Generator::new(2022, 11, 8, year2022::day11::generate),
+    Generator::new(2022, 12, 160, year2022::day12::generate),
+    Generator::new(2022, 13, 150, year2022::day13::generate),
+    Generator::new(2022, 14, 150, year2022::day14::generate),
+    Generator::new(2022, 15, 30, year2022::day15::generate),
+    Generator::new(2022, 16, 60, year2022::day16::generate),
+    Generator::new(2022, 21, 2000, year2022::day21::generate),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
+
+/// A name of `len` lowercase letters that is not in `taken`, which it is then added to.
+pub(crate) fn unique_name(
+    rng: &mut Rng,
+    len: usize,
+    taken: &mut std::collections::HashSet<String>,
+) -> String {
+    loop {
+        let name: String = (0..len).map(|_| rng.lowercase()).collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Sonar sweeps of `size` depth measurements, going down and up at random but mostly down.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut depth = rng.u32(100..200);
+    for _ in 0..size {
+        writeln!(out, "{depth}")?;
+        depth = (depth + rng.u32(0..30)).saturating_sub(10).max(1);
+    }
+    Ok(())
+}
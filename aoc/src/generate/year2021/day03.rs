@@ -0,0 +1,15 @@
+//! Diagnostic reports of `size` 12 bit binary numbers.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+const BITS: usize = 12;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let number = rng.u32(0..1 << BITS);
+        writeln!(out, "{number:0BITS$b}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,4 @@
+//! Inputs for the days of 2021.
+
+pub mod day01;
+pub mod day03;
@@ -0,0 +1,17 @@
+//! The calories carried by `size` elves, at least three.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for elf in 0..size.max(3) {
+        if elf > 0 {
+            writeln!(out)?;
+        }
+        for _ in 0..rng.usize(1..=15) {
+            writeln!(out, "{}", rng.u32(1000..=60_000))?;
+        }
+    }
+    Ok(())
+}
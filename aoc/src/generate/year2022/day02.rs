@@ -0,0 +1,14 @@
+//! A strategy guide of `size` rounds.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let opponent = rng.choice(['A', 'B', 'C']).unwrap();
+        let response = rng.choice(['X', 'Y', 'Z']).unwrap();
+        writeln!(out, "{opponent} {response}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! The rucksacks of `size` groups of three elves.
+//!
+//! The letters other than a group's badge are split between its three rucksacks, so that the
+//! badge is the only item all three carry. Each rucksack splits its letters between its
+//! compartments again, and then puts one of its letters, or the badge, into both.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    for _ in 0..size {
+        rng.shuffle(&mut letters);
+        let (badge, rest) = letters.split_last().unwrap();
+
+        for own in rest.chunks(rest.len() / 3) {
+            let shared = *own.get(rng.usize(..=own.len())).unwrap_or(badge);
+            let len = rng.usize(2..=16);
+            let (first, second) = own.split_at(own.len() / 2);
+            let mut compartments = [first, second].map(|letters| {
+                let letters: Vec<char> = letters.iter().copied().filter(|&c| c != shared).collect();
+                let mut items: Vec<char> = (0..len)
+                    .map(|_| rng.choice(&letters).copied().unwrap())
+                    .collect();
+                items[rng.usize(..len)] = shared;
+                items
+            });
+
+            if shared != *badge {
+                let at = compartments[0].iter().position(|&c| c != shared).unwrap();
+                compartments[0][at] = *badge;
+            }
+            let [first, second] = compartments.map(String::from_iter);
+            writeln!(out, "{first}{second}")?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! `size` pairs of section assignments.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut range = || {
+        let low = rng.u32(1..=99);
+        (low, rng.u32(low..=99))
+    };
+    for _ in 0..size {
+        let ((a, b), (c, d)) = (range(), range());
+        writeln!(out, "{a}-{b},{c}-{d}")?;
+    }
+    Ok(())
+}
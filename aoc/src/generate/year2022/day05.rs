@@ -0,0 +1,44 @@
+//! A drawing of up to nine stacks of crates, followed by `size` moves that each take crates from
+//! a stack that has them.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut stacks: Vec<Vec<char>> = (0..rng.usize(2..=9))
+        .map(|_| (0..rng.usize(1..=8)).map(|_| rng.uppercase()).collect())
+        .collect();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        writeln!(out, "{}", row.join(" "))?;
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {n} ")).collect();
+    writeln!(out, "{}", numbers.join(" "))?;
+    writeln!(out)?;
+
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.usize(..stacks.len());
+            if !stacks[from].is_empty() {
+                break from;
+            }
+        };
+        let to = (from + rng.usize(1..stacks.len())) % stacks.len();
+        let count = rng.usize(1..=stacks[from].len());
+
+        let moved = stacks[from].len() - count;
+        let crates: Vec<char> = stacks[from].drain(moved..).collect();
+        stacks[to].extend(crates);
+        writeln!(out, "move {count} from {} to {}", from + 1, to + 1)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! A datastream of `size` characters, at least 42.
+//!
+//! The stream uses only three letters up to the start-of-packet marker a third of the way in,
+//! and no more than thirteen letters up to the start-of-message marker two thirds of the way in,
+//! so that neither marker shows up before it.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+/// How many characters are written at once.
+const CHUNK: usize = 1 << 16;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let size = size.max(42);
+    let packet = size / 3;
+    let message = (size * 2 / 3).max(packet + 4);
+
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+    let mut chunk = Vec::with_capacity(CHUNK);
+    for i in 0..size {
+        let c = if (packet..packet + 4).contains(&i) {
+            letters[i - packet]
+        } else if (message..message + 14).contains(&i) {
+            letters[i - message]
+        } else if i < packet {
+            letters[rng.usize(..3)]
+        } else if i < message {
+            letters[rng.usize(..13)]
+        } else {
+            letters[rng.usize(..letters.len())]
+        };
+        chunk.push(c);
+
+        if chunk.len() == CHUNK {
+            out.write_all(&chunk)?;
+            chunk.clear();
+        }
+    }
+    chunk.push(b'\n');
+    out.write_all(&chunk)
+}
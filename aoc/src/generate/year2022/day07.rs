@@ -0,0 +1,89 @@
+//! A terminal transcript that lists each directory of a file system with `size` files and
+//! directories once.
+//!
+//! The files add up to between 40 000 000 and 70 000 000, which the disk has room for but not
+//! with the 30 000 000 free that the update needs. As every file takes at least a byte, there
+//! are at most [`MAX_FILES`] of them, and any records beyond that are directories.
+
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+use fastrand::Rng;
+
+use super::super::unique_name;
+
+const DISK: u64 = 70_000_000;
+const USED_AT_LEAST: u64 = 40_000_000;
+/// The most files that can add up to a total in the range the sizes are scaled to.
+const MAX_FILES: u64 = (DISK - USED_AT_LEAST - 1) / 2;
+
+#[derive(Default)]
+struct Dir {
+    files: Vec<(String, u64)>,
+    dirs: Vec<(String, usize)>,
+    names: HashSet<String>,
+}
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut dirs = vec![Dir::default()];
+    let mut files = 0;
+    for i in 0..size {
+        let parent = rng.usize(..dirs.len());
+        let len = rng.usize(1..=8);
+        let mut name = unique_name(rng, len, &mut dirs[parent].names);
+        if i > 0 && (rng.u8(..4) == 0 || files == MAX_FILES) {
+            dirs.push(Dir::default());
+            let dir = dirs.len() - 1;
+            dirs[parent].dirs.push((name, dir));
+        } else {
+            if rng.bool() {
+                let extension: String = (0..rng.usize(1..=3)).map(|_| rng.lowercase()).collect();
+                name = format!("{name}.{extension}");
+            }
+            // The weight of the file for now, the sizes are only known once all files are there.
+            // Most files are small, which leaves some directories small enough for part 1
+            dirs[parent].files.push((name, rng.u64(1..=100).pow(3)));
+            files += 1;
+        }
+    }
+
+    // Scales the weights to sizes that add up to the total, give or take a byte per file
+    let total = rng.u64(USED_AT_LEAST + files + 1..=DISK - files);
+    let weights: u64 = dirs.iter().flat_map(|d| &d.files).map(|(_, w)| w).sum();
+    for dir in &mut dirs {
+        for (_, size) in &mut dir.files {
+            *size = (*size * total / weights).max(1);
+        }
+    }
+
+    writeln!(out, "$ cd /")?;
+    list(rng, &dirs, 0, out)
+}
+
+fn list(rng: &mut Rng, dirs: &[Dir], dir: usize, out: &mut dyn Write) -> io::Result<()> {
+    let dir = &dirs[dir];
+    writeln!(out, "$ ls")?;
+    let mut entries: Vec<String> = dir
+        .dirs
+        .iter()
+        .map(|(name, _)| format!("dir {name}"))
+        .collect();
+    entries.extend(
+        dir.files
+            .iter()
+            .map(|(name, size)| format!("{size} {name}")),
+    );
+    rng.shuffle(&mut entries);
+    for entry in entries {
+        writeln!(out, "{entry}")?;
+    }
+
+    for (name, sub) in &dir.dirs {
+        writeln!(out, "$ cd {name}")?;
+        list(rng, dirs, *sub, out)?;
+        writeln!(out, "$ cd ..")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! A square grid of trees `size` trees wide.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let row: String = (0..size).map(|_| rng.digit(10)).collect();
+        writeln!(out, "{row}")?;
+    }
+    Ok(())
+}
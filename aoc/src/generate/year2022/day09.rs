@@ -0,0 +1,13 @@
+//! `size` motions of the head of the rope.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let direction = rng.choice(['R', 'L', 'U', 'D']).unwrap();
+        writeln!(out, "{direction} {}", rng.u8(1..=20))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! A program of `size` instructions, or more if it would not run for the 240 cycles the screen
+//! takes to draw.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+const CYCLES: usize = 240;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut cycles = 0;
+    let mut x: i32 = 1;
+    let mut instructions = 0;
+    while instructions < size || cycles < CYCLES {
+        if rng.u8(0..3) == 0 {
+            writeln!(out, "noop")?;
+            cycles += 1;
+        } else {
+            // Keeps the sprite around the screen, so that something is drawn
+            let mut value = rng.i32(-15..=15);
+            if !(-5..45).contains(&(x + value)) || value == 0 {
+                value = if x < 20 {
+                    rng.i32(1..=15)
+                } else {
+                    rng.i32(-15..=-1)
+                };
+            }
+            x += value;
+            writeln!(out, "addx {value}")?;
+            cycles += 2;
+        }
+        instructions += 1;
+    }
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! Notes on `size` monkeys, at least two.
+//!
+//! Like in the real notes the monkeys test with prime divisors, so that the worry levels can be
+//! kept small in part 2, and at most one of them squares the worry level. Notes on which part 1
+//! would overflow a worry level are thrown away and generated again, without a monkey that
+//! squares after a few tries, as with few monkeys the same items keep coming back to it.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Plus(u64),
+    Times(u64),
+    Square,
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut tries = 0;
+    let monkeys = loop {
+        tries += 1;
+        let monkeys = monkeys(rng, size.max(2), tries <= 20);
+        if part_one_fits(&monkeys) {
+            break monkeys;
+        }
+    };
+
+    for (i, monkey) in monkeys.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+        let operation = match monkey.operation {
+            Operation::Plus(n) => format!("old + {n}"),
+            Operation::Times(n) => format!("old * {n}"),
+            Operation::Square => "old * old".to_string(),
+        };
+        writeln!(out, "Monkey {i}:")?;
+        writeln!(out, "  Starting items: {}", items.join(", "))?;
+        writeln!(out, "  Operation: new = {operation}")?;
+        writeln!(out, "  Test: divisible by {}", monkey.divisor)?;
+        writeln!(out, "    If true: throw to monkey {}", monkey.if_true)?;
+        writeln!(out, "    If false: throw to monkey {}", monkey.if_false)?;
+    }
+    Ok(())
+}
+
+fn monkeys(rng: &mut Rng, count: usize, square: bool) -> Vec<Monkey> {
+    let squaring = square.then(|| rng.usize(..count));
+    (0..count)
+        .map(|i| {
+            // Monkeys never throw to themselves, and throw to two different monkeys if they can
+            let if_true = (i + rng.usize(1..count)) % count;
+            let mut if_false = (i + rng.usize(1..count)) % count;
+            if count > 2 {
+                while if_false == if_true || if_false == i {
+                    if_false = rng.usize(..count);
+                }
+            }
+
+            Monkey {
+                items: (0..rng.usize(1..=8)).map(|_| rng.u64(50..100)).collect(),
+                operation: match rng.u8(..2) {
+                    _ if squaring == Some(i) => Operation::Square,
+                    0 => Operation::Plus(rng.u64(1..=8)),
+                    _ => Operation::Times(rng.u64(2..=19)),
+                },
+                divisor: rng.choice(PRIMES).unwrap(),
+                if_true,
+                if_false,
+            }
+        })
+        .collect()
+}
+
+/// Plays the 20 rounds of part 1, checking that no worry level overflows.
+fn part_one_fits(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    for _ in 0..20 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let worry = match monkey.operation {
+                    Operation::Plus(n) => item.checked_add(n),
+                    Operation::Times(n) => item.checked_mul(n),
+                    Operation::Square => item.checked_mul(item),
+                };
+                let Some(worry) = worry.map(|w| w / 3) else {
+                    return false;
+                };
+                let to = if worry % monkey.divisor == 0 {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+                items[to].push(worry);
+            }
+        }
+    }
+    true
+}
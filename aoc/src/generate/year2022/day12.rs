@@ -0,0 +1,48 @@
+//! A heightmap `size` squares wide and a quarter as high, at least 26 by 5.
+//!
+//! A path from the start along its row and then up or down its column to the destination
+//! climbs from `a` to `z` one letter at a time, so that the destination can be reached. The
+//! other squares get random heights.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let width = size.max(26);
+    let height = (width / 4).max(5);
+    let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.u8(b'a'..=b'z')).collect())
+        .collect();
+
+    let start = (0, rng.usize(..height));
+    let end = (rng.usize(25..width), rng.usize(..height));
+    let mut path: Vec<(usize, usize)> = (0..=end.0).map(|x| (x, start.1)).collect();
+    let (low, high) = (start.1.min(end.1), start.1.max(end.1));
+    let column: Vec<usize> = if end.1 > start.1 {
+        (low + 1..=high).collect()
+    } else {
+        (low..high).rev().collect()
+    };
+    path.extend(column.into_iter().map(|y| (end.0, y)));
+
+    // The path is at least 26 squares long, which leaves room to climb a letter at a time
+    let mut climbs = vec![true; 25];
+    climbs.resize(path.len() - 1, false);
+    rng.shuffle(&mut climbs);
+    let mut level = b'a';
+    for (i, &(x, y)) in path.iter().enumerate() {
+        if i > 0 && climbs[i - 1] {
+            level += 1;
+        }
+        map[y][x] = level;
+    }
+    map[start.1][start.0] = b'S';
+    map[end.1][end.0] = b'E';
+
+    for row in map {
+        out.write_all(&row)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
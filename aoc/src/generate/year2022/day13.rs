@@ -0,0 +1,74 @@
+//! `size` pairs of packets, where the packets of a pair are never equal, so that they are either
+//! in the right order or not.
+
+use std::{
+    cmp::Ordering,
+    io::{self, Write},
+};
+
+use fastrand::Rng;
+
+enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn random(rng: &mut Rng, depth: usize) -> Packet {
+        if depth > 0 && rng.u8(..3) == 0 {
+            return Packet::Int(rng.u32(0..=10));
+        }
+        let len = if depth < 4 { rng.usize(0..=5) } else { 0 };
+        Packet::List((0..len).map(|_| Packet::random(rng, depth + 1)).collect())
+    }
+
+    /// Compares packets the way the distress signal orders them.
+    fn compare(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| a.compare(b))
+                .find(|o| o.is_ne())
+                .unwrap_or(a.len().cmp(&b.len())),
+            (Packet::Int(a), list) => Packet::List(vec![Packet::Int(*a)]).compare(list),
+            (list, Packet::Int(b)) => list.compare(&Packet::List(vec![Packet::Int(*b)])),
+        }
+    }
+}
+
+impl std::fmt::Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Int(n) => write!(f, "{n}"),
+            Packet::List(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for pair in 0..size {
+        if pair > 0 {
+            writeln!(out)?;
+        }
+        let first = Packet::random(rng, 0);
+        let second = loop {
+            let second = Packet::random(rng, 0);
+            if first.compare(&second).is_ne() {
+                break second;
+            }
+        };
+        writeln!(out, "{first}\n{second}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//! A scan of `size` paths of rock, at least one, around where the sand pours in.
+//!
+//! The rock is kept between x 410 and 590 and above y 176, and the first path is a ledge right
+//! under the source, so that some sand comes to rest. The solution only checks that sand falls
+//! off the right of the scan after reading past its edge, so two short paths far out on both
+//! sides and below all the other rock widen the scan without catching any sand. Scans on which
+//! the sand in part 1 would pile up to the source instead of falling into the abyss are thrown
+//! away and generated again.
+
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+use fastrand::Rng;
+
+const LEFT: u32 = 410;
+const RIGHT: u32 = 590;
+const TOP: u32 = 13;
+const BOTTOM: u32 = 175;
+
+type Path = Vec<(u32, u32)>;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let paths = loop {
+        let paths = paths(rng, size);
+        if sand_falls_off(&paths) {
+            break paths;
+        }
+    };
+
+    let floor = BOTTOM + 1;
+    let guards = [
+        vec![(LEFT - 10, floor), (LEFT - 9, floor)],
+        vec![(RIGHT + 9, floor), (RIGHT + 10, floor)],
+    ];
+    for path in paths.iter().chain(&guards) {
+        let points: Vec<String> = path.iter().map(|(x, y)| format!("{x},{y}")).collect();
+        writeln!(out, "{}", points.join(" -> "))?;
+    }
+    Ok(())
+}
+
+fn paths(rng: &mut Rng, count: usize) -> Vec<Path> {
+    let y = rng.u32(TOP..=BOTTOM / 2);
+    let mut paths = vec![vec![(rng.u32(494..=499), y), (rng.u32(501..=506), y)]];
+
+    while paths.len() < count {
+        let mut point = (rng.u32(LEFT..=RIGHT), rng.u32(TOP..=BOTTOM));
+        let mut path = vec![point];
+        let mut horizontal = rng.bool();
+        for _ in 0..rng.usize(1..=5) {
+            let step = rng.u32(1..=8);
+            point = if horizontal {
+                let x = if rng.bool() {
+                    point.0 + step
+                } else {
+                    point.0.saturating_sub(step)
+                };
+                (x.clamp(LEFT, RIGHT), point.1)
+            } else {
+                let y = if rng.bool() {
+                    point.1 + step
+                } else {
+                    point.1.saturating_sub(step)
+                };
+                (point.0, y.clamp(TOP, BOTTOM))
+            };
+            if path.last() != Some(&point) {
+                path.push(point);
+            }
+            horizontal = !horizontal;
+        }
+        if path.len() > 1 {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Whether the sand in part 1 falls into the abyss before it piles up to the source.
+fn sand_falls_off(paths: &[Path]) -> bool {
+    let mut filled = HashSet::new();
+    for path in paths {
+        for pair in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    filled.insert((x, y));
+                }
+            }
+        }
+    }
+
+    loop {
+        let (mut x, mut y) = (500, 0);
+        loop {
+            if y > BOTTOM {
+                return true;
+            }
+            if let Some(next) = [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&next| !filled.contains(&(next, y + 1)))
+            {
+                x = next;
+                y += 1;
+            } else if y == 0 {
+                return false;
+            } else {
+                filled.insert((x, y));
+                break;
+            }
+        }
+    }
+}
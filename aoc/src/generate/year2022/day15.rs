@@ -0,0 +1,91 @@
+//! Up to `size` sensors, at least the four around the distress beacon, with their closest
+//! beacons.
+//!
+//! The distress beacon is near the middle of the search area, and four sensors diagonally away
+//! from it in each direction reach right up to it, which covers the rest of the search area. The
+//! other sensors are placed at random, inside the search area or around it, and do not reach the
+//! distress beacon. No beacon is closer to a sensor than its own, so these sensors are mostly
+//! outside the search area, where the four sensors do not reach, and when none fit anymore there
+//! are fewer than `size`. On the row of part 1 no sensor reaches farther out than the solution
+//! looks.
+
+use std::io::{self, Write};
+
+use fastrand::Rng;
+
+const EDGE: i64 = 4_000_000;
+/// The row of part 1, and how far along it the solution looks.
+const ROW: i64 = 2_000_000;
+const ROW_EDGE: i64 = 9_000_000;
+
+type Position = (i64, i64);
+
+struct Sensor {
+    position: Position,
+    beacon: Position,
+    range: i64,
+}
+
+fn distance(a: Position, b: Position) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let distress = (
+        rng.i64(EDGE * 9 / 20..=EDGE * 11 / 20),
+        rng.i64(EDGE * 9 / 20..=EDGE * 11 / 20),
+    );
+
+    // A sensor `a` away along both axes with a range of `2a - 1` covers the square between it
+    // and the distress beacon, apart from the beacon, so `a` is as far as the farthest edge.
+    // Their beacons lie straight out to the side, away from the other three sensors.
+    let mut sensors: Vec<Sensor> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .into_iter()
+        .map(|(dx, dy)| {
+            let edge = |d: i64, at: i64| if d > 0 { EDGE - at } else { at };
+            let a = edge(dx, distress.0).max(edge(dy, distress.1));
+            let position = (distress.0 + dx * a, distress.1 + dy * a);
+            let range = 2 * a - 1;
+            Sensor {
+                position,
+                beacon: (position.0 + dx * range, position.1),
+                range,
+            }
+        })
+        .collect();
+
+    let mut tries = 0;
+    while sensors.len() < size && tries < 1000 * size {
+        tries += 1;
+        let position = (rng.i64(-EDGE..=2 * EDGE), rng.i64(-EDGE..=2 * EDGE));
+        let range = rng.i64(1..distance(position, distress).min(EDGE));
+        let dx = rng.i64(-range..=range);
+        let dy = (range - dx.abs()) * if rng.bool() { 1 } else { -1 };
+        let sensor = Sensor {
+            position,
+            beacon: (position.0 + dx, position.1 + dy),
+            range,
+        };
+
+        let row = (sensor.range - (sensor.position.1 - ROW).abs()).max(0);
+        let fits = (sensor.position.0 - row).abs() < ROW_EDGE
+            && (sensor.position.0 + row).abs() < ROW_EDGE
+            && sensors.iter().all(|other| {
+                distance(other.position, sensor.beacon) > other.range
+                    && distance(sensor.position, other.beacon) > sensor.range
+            });
+        if fits {
+            sensors.push(sensor);
+        }
+    }
+
+    rng.shuffle(&mut sensors);
+    for sensor in sensors {
+        let ((x, y), (beacon_x, beacon_y)) = (sensor.position, sensor.beacon);
+        writeln!(
+            out,
+            "Sensor at x={x}, y={y}: closest beacon is at x={beacon_x}, y={beacon_y}"
+        )?;
+    }
+    Ok(())
+}
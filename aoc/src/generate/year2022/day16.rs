@@ -0,0 +1,62 @@
+//! A scan of `size` valves, at least two, starting with `AA`.
+//!
+//! Like in the real scan most valves have no flow rate, `AA` among them, and the tunnels connect
+//! all the valves: each valve after the first is connected to one before it, and then a few more
+//! tunnels are added. Tunnels go both ways.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    io::{self, Write},
+};
+
+use fastrand::Rng;
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    // There are only so many names of two letters
+    let count = size.clamp(2, 26 * 26);
+    let mut taken = HashSet::from(["AA".to_string()]);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < count {
+        let name: String = (0..2).map(|_| rng.uppercase()).collect();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        tunnels[a].insert(b);
+        tunnels[b].insert(a);
+    };
+    for valve in 1..count {
+        connect(valve, rng.usize(..valve));
+    }
+    for _ in 0..count / 3 {
+        let (a, b) = (rng.usize(..count), rng.usize(..count));
+        if a != b {
+            connect(a, b);
+        }
+    }
+
+    let mut order: Vec<usize> = (0..count).collect();
+    rng.shuffle(&mut order);
+    for valve in order {
+        let flow_rate = if valve == 0 || rng.u8(..4) > 0 {
+            0
+        } else {
+            rng.u32(1..=25)
+        };
+        let to: Vec<&str> = tunnels[valve].iter().map(|&t| names[t].as_str()).collect();
+        let tunnels = if to.len() == 1 {
+            format!("tunnel leads to valve {}", to[0])
+        } else {
+            format!("tunnels lead to valves {}", to.join(", "))
+        };
+        writeln!(
+            out,
+            "Valve {} has flow rate={flow_rate}; {tunnels}",
+            names[valve]
+        )?;
+    }
+    Ok(())
+}
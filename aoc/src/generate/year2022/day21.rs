@@ -0,0 +1,126 @@
+//! The jobs of about `size` monkeys.
+//!
+//! `root` adds up two monkeys, and `humn` is only listened to on the left. The monkeys between
+//! `root` and `humn` each combine the number from below with a number that does not depend on
+//! `humn` by adding, subtracting or multiplying, so that a single number yelled by `humn`
+//! matches the two sides. That number is kept small for the reference solver of part 2, which
+//! tries every number from 0, and the numbers that do not depend on `humn` may also come from
+//! dividing without a remainder. No monkey ever yells a number beyond [`LIMIT`].
+
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+use fastrand::Rng;
+
+use super::super::unique_name;
+
+const LIMIT: i64 = 1_000_000_000_000;
+
+/// What the monkey `humn` yells for part 2 is below this.
+const HUMN_BELOW: i64 = 1000;
+
+struct Monkeys {
+    jobs: Vec<String>,
+    taken: HashSet<String>,
+}
+
+impl Monkeys {
+    fn add(&mut self, rng: &mut Rng, job: String) -> String {
+        let name = unique_name(rng, 4, &mut self.taken);
+        self.jobs.push(format!("{name}: {job}"));
+        name
+    }
+
+    /// A monkey that ends up yelling `value`, from about `count` monkeys.
+    fn yelling(&mut self, rng: &mut Rng, value: i64, count: usize) -> String {
+        if value >= 0 && count < 3 {
+            return self.add(rng, value.to_string());
+        }
+
+        let (left, operator, right) = match rng.u8(..4) {
+            _ if value < 0 => {
+                let left = rng.i64(0..=100);
+                (left, '-', left - value)
+            }
+            0 => {
+                let left = rng.i64(0..=value);
+                (left, '+', value - left)
+            }
+            2 => match (2..=9).rev().find(|d| value != 0 && value % d == 0) {
+                Some(divisor) => (value / divisor, '*', divisor),
+                None => (value + 10, '-', 10),
+            },
+            3 if value.abs() * 9 <= LIMIT => {
+                let divisor = rng.i64(2..=9);
+                (value * divisor, '/', divisor)
+            }
+            _ => {
+                let right = rng.i64(1..=100);
+                (value + right, '-', right)
+            }
+        };
+        let split = rng.usize(1..count.max(3) - 1);
+        let left = self.yelling(rng, left, split);
+        let right = self.yelling(rng, right, count.max(3) - 1 - split);
+        self.add(rng, format!("{left} {operator} {right}"))
+    }
+}
+
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut monkeys = Monkeys {
+        jobs: Vec::new(),
+        taken: HashSet::from(["root".to_string(), "humn".to_string()]),
+    };
+    let humn = rng.i64(1..=5000);
+    let matching = rng.i64(0..HUMN_BELOW);
+
+    // Each monkey on the way up yells `factor * humn + offset`, which is kept small enough for
+    // any number `humn` yells in either part
+    let steps = (size / 20).clamp(1, 50);
+    let count = size.saturating_sub(steps + 2) / (steps + 1);
+    let (mut factor, mut offset) = (1_i64, 0_i64);
+    let mut below = "humn".to_string();
+    let fits = |factor: i64, offset: i64| factor.abs() * 5000 + offset.abs() <= LIMIT / 100;
+    for _ in 0..steps {
+        let number = rng.i64(1..=100);
+        let multiplier = rng.i64(2..=5);
+        let (operation, next) = match rng.u8(..4) {
+            0 => ("+", (factor, offset + number)),
+            1 => ("-", (factor, offset - number)),
+            2 => ("from", (-factor, number - offset)),
+            _ if fits(factor * multiplier, offset * multiplier) => {
+                ("*", (factor * multiplier, offset * multiplier))
+            }
+            _ => ("+", (factor, offset + number)),
+        };
+        if !fits(next.0, next.1) {
+            continue;
+        }
+        (factor, offset) = next;
+
+        let other = match operation {
+            "*" => multiplier,
+            _ => number,
+        };
+        let other = monkeys.yelling(rng, other, count);
+        let job = match operation {
+            "from" => format!("{other} - {below}"),
+            "-" => format!("{below} - {other}"),
+            op if rng.bool() => format!("{below} {op} {other}"),
+            op => format!("{other} {op} {below}"),
+        };
+        below = monkeys.add(rng, job);
+    }
+
+    let right = monkeys.yelling(rng, factor * matching + offset, count);
+    monkeys.jobs.push(format!("root: {below} + {right}"));
+    monkeys.jobs.push(format!("humn: {humn}"));
+
+    rng.shuffle(&mut monkeys.jobs);
+    for job in monkeys.jobs {
+        writeln!(out, "{job}")?;
+    }
+    Ok(())
+}
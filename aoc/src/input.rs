@@ -0,0 +1,84 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::cache::Cache;
+
+/// An input along with the file it was read from.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Read(PathBuf, io::Error),
+    #[cfg(feature = "download_input")]
+    Download(crate::client::Error),
+    #[cfg(not(feature = "download_input"))]
+    Missing(PathBuf),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Read(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+            #[cfg(feature = "download_input")]
+            Error::Download(err) => write!(f, "failed to download input: {err}"),
+            #[cfg(not(feature = "download_input"))]
+            Error::Missing(path) => write!(
+                f,
+                "{} is missing and the download_input feature is not enabled",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Reads an input file, a path of `-` reads from stdin.
+pub fn read(path: &Path) -> Result<Input, Error> {
+    if path == Path::new("-") {
+        let path = PathBuf::from("<stdin>");
+        let text = io::read_to_string(io::stdin()).map_err(|err| Error::Read(path.clone(), err))?;
+        return Ok(Input { path, text });
+    }
+
+    let text = fs::read_to_string(path).map_err(|err| Error::Read(path.to_path_buf(), err))?;
+    Ok(Input {
+        path: path.to_path_buf(),
+        text,
+    })
+}
+
+/// Reads the input for a day from `path`, falling back to the cache if it does not exist.
+///
+/// With the `download_input` feature an input that is not cached yet is downloaded into the
+/// cache.
+pub fn load(year: u16, day: u8, path: &Path) -> Result<Input, Error> {
+    if path.exists() {
+        return read(path);
+    }
+
+    let cache = Cache::from_env();
+    let cached = cache.input_path(year, day);
+    if cached.exists() {
+        return read(&cached);
+    }
+
+    #[cfg(feature = "download_input")]
+    {
+        let text = crate::client::Client::from_env()
+            .and_then(|client| client.cached_input(&cache, year, day))
+            .map_err(Error::Download)?;
+
+        Ok(Input { path: cached, text })
+    }
+
+    #[cfg(not(feature = "download_input"))]
+    Err(Error::Missing(path.to_path_buf()))
+}
@@ -0,0 +1,292 @@
+//! Statistics of a private leaderboard, read from the JSON the site serves for it at
+//! `/{year}/leaderboard/private/view/{id}.json`.
+//!
+//! The JSON has the time each member got each star, as seconds since the epoch. Puzzles unlock
+//! at midnight EST (05:00 UTC), so the times are shown relative to that. Points are given the
+//! way the site does for local scores: the first member to get a star gets as many points as
+//! the leaderboard has members, the next one a point less and so on.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::Deserialize;
+
+/// The site asks not to fetch a leaderboard more often than this, in seconds.
+pub const REFRESH_AFTER: u64 = 15 * 60;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    #[serde(deserialize_with = "year")]
+    pub event: u16,
+    pub owner_id: u64,
+    members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// The days the member has stars for, by day and then by part.
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+struct Star {
+    get_star_ts: i64,
+    /// Breaks ties between stars gotten in the same second.
+    #[serde(default)]
+    star_index: u64,
+}
+
+impl Member {
+    /// The member's name, or how the site shows members without one.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn star(&self, day: u8, part: u8) -> Option<Star> {
+        self.completion_day_level.get(&day)?.get(&part).copied()
+    }
+}
+
+/// When a member got the stars of a day, in seconds after the puzzle unlocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StarTimes {
+    pub part_one: Option<i64>,
+    pub part_two: Option<i64>,
+}
+
+impl StarTimes {
+    /// How long part 2 took after part 1.
+    pub fn delta(&self) -> Option<i64> {
+        Some(self.part_two? - self.part_one?)
+    }
+}
+
+/// The members in the order they stood after a day, with their local score up to that day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub day: u8,
+    /// Member ids and scores, best first.
+    pub scores: Vec<(u64, u32)>,
+}
+
+impl Standing {
+    /// The place of a member, from 1.
+    pub fn place(&self, id: u64) -> Option<usize> {
+        self.scores
+            .iter()
+            .position(|&(m, _)| m == id)
+            .map(|i| i + 1)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// The members, ordered by local score and then by name, like on the site.
+    pub fn members(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.display_name(), m.id));
+        members
+    }
+
+    pub fn member(&self, id: u64) -> Option<&Member> {
+        self.members.values().find(|m| m.id == id)
+    }
+
+    /// The days anyone has a star for, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys().copied())
+            .collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// When a member got the stars of a day.
+    pub fn star_times(&self, member: &Member, day: u8) -> StarTimes {
+        let unlock = unlock_time(self.event, day);
+        let time = |part| member.star(day, part).map(|s| s.get_star_ts - unlock);
+        StarTimes {
+            part_one: time(1),
+            part_two: time(2),
+        }
+    }
+
+    /// The points each member got for a star, by member id. Members without the star get none.
+    pub fn points(&self, day: u8, part: u8) -> BTreeMap<u64, u32> {
+        let mut got: Vec<(Star, u64)> = self
+            .members
+            .values()
+            .filter_map(|m| Some((m.star(day, part)?, m.id)))
+            .collect();
+        got.sort_by_key(|(star, id)| (star.get_star_ts, star.star_index, *id));
+
+        let count = self.members.len() as u32;
+        got.into_iter()
+            .enumerate()
+            .map(|(place, (_, id))| (id, count - place as u32))
+            .collect()
+    }
+
+    /// The standings after each day, counting every star of the days up to it no matter when
+    /// it was gotten.
+    pub fn history(&self) -> Vec<Standing> {
+        let mut totals: BTreeMap<u64, u32> = self.members.values().map(|m| (m.id, 0)).collect();
+        let names: BTreeMap<u64, String> = self
+            .members
+            .values()
+            .map(|m| (m.id, m.display_name()))
+            .collect();
+
+        let mut history = Vec::new();
+        for day in 1..=self.days().last().copied().unwrap_or(0) {
+            for part in [1, 2] {
+                for (id, points) in self.points(day, part) {
+                    *totals.get_mut(&id).unwrap() += points;
+                }
+            }
+            let mut scores: Vec<(u64, u32)> = totals.iter().map(|(&id, &s)| (id, s)).collect();
+            scores.sort_by_key(|&(id, score)| (std::cmp::Reverse(score), &names[&id], id));
+            history.push(Standing { day, scores });
+        }
+        history
+    }
+
+    /// A report of every day's star times and the standings after each day, or only of `day`.
+    pub fn report(&self, day: Option<u8>) -> Report<'_> {
+        Report {
+            leaderboard: self,
+            day,
+        }
+    }
+}
+
+/// See [`Leaderboard::report`].
+pub struct Report<'a> {
+    leaderboard: &'a Leaderboard,
+    day: Option<u8>,
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let board = self.leaderboard;
+        let members = board.members();
+        let width = members
+            .iter()
+            .map(|m| m.display_name().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("member".len());
+        let days: Vec<u8> = board
+            .days()
+            .into_iter()
+            .filter(|&d| self.day.is_none_or(|day| d == day))
+            .collect();
+        let history = board.history();
+
+        for &day in &days {
+            writeln!(f, "{} day {day}", board.event)?;
+            writeln!(
+                f,
+                "  {:<width$} {:>11} {:>11} {:>11} {:>6}",
+                "member", "part 1", "part 2", "delta", "place"
+            )?;
+
+            // Members with both stars first, the fastest first
+            let mut times: Vec<(&Member, StarTimes)> = members
+                .iter()
+                .map(|&m| (m, board.star_times(m, day)))
+                .filter(|(_, t)| t.part_one.is_some())
+                .collect();
+            times.sort_by_key(|(m, t)| (t.part_two.is_none(), t.part_two, t.part_one, m.id));
+
+            let standing = &history[usize::from(day) - 1];
+            for (member, t) in times {
+                writeln!(
+                    f,
+                    "  {:<width$} {:>11} {:>11} {:>11} {:>6}",
+                    member.display_name(),
+                    duration(t.part_one),
+                    duration(t.part_two),
+                    duration(t.delta()),
+                    standing.place(member.id).unwrap(),
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        if self.day.is_none() && !history.is_empty() {
+            writeln!(f, "places after each day")?;
+            write!(f, "  {:<width$}", "member")?;
+            for standing in &history {
+                write!(f, " {:>3}", standing.day)?;
+            }
+            writeln!(f, " {:>6}", "score")?;
+            for member in members {
+                write!(f, "  {:<width$}", member.display_name())?;
+                for standing in &history {
+                    write!(f, " {:>3}", standing.place(member.id).unwrap())?;
+                }
+                let last = history.last().unwrap();
+                let (_, score) = last.scores.iter().find(|(id, _)| *id == member.id).unwrap();
+                writeln!(f, " {score:>6}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A time in seconds as `h:mm:ss`, `-` if there is none. Stars gotten more than a day after the
+/// puzzle unlocked start with the days, as `2d 3:04:05`.
+pub fn duration(seconds: Option<i64>) -> String {
+    let Some(seconds) = seconds else {
+        return "-".to_string();
+    };
+    let (days, rest) = (seconds / 86400, seconds % 86400);
+    let time = format!("{}:{:02}:{:02}", rest / 3600, rest / 60 % 60, rest % 60);
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/// When the puzzle of a day unlocks, as seconds since the epoch.
+pub fn unlock_time(year: u16, day: u8) -> i64 {
+    // Days since the epoch of December 1st, from Howard Hinnant's `days_from_civil`, which is
+    // simple for a date after February
+    let year = i64::from(year);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (12 - 3) + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let december = era * 146097 + day_of_era - 719468;
+
+    (december + i64::from(day) - 1) * 86400 + 5 * 3600
+}
+
+/// The event is a year, which the JSON has as a string.
+fn year<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Event {
+        Number(u16),
+        String(String),
+    }
+
+    match Event::deserialize(deserializer)? {
+        Event::Number(year) => Ok(year),
+        Event::String(year) => year.parse().map_err(serde::de::Error::custom),
+    }
+}
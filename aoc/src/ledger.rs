@@ -0,0 +1,214 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{cache::Cache, Part};
+
+/// The site's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answered too recently, nothing was checked.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part has already been solved, nothing was checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Whether the site actually checked the answer.
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, Verdict::RateLimited { .. } | Verdict::AlreadySolved)
+    }
+
+    fn to_token(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited { .. } => "rate_limited",
+            Verdict::AlreadySolved => "already_solved",
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Self> {
+        Some(match token {
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "rate_limited" => Verdict::RateLimited { wait: None },
+            "already_solved" => Verdict::AlreadySolved,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::TooHigh => write!(f, "wrong, the answer is too high"),
+            Verdict::TooLow => write!(f, "wrong, the answer is too low"),
+            Verdict::Wrong => write!(f, "wrong answer"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "answered too recently, {wait} left to wait")
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "answered too recently"),
+            Verdict::AlreadySolved => write!(f, "this part has already been solved"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why a guess was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved { answer: String },
+    Repeated { verdict: Verdict },
+    AtLeast { too_high: String },
+    AtMost { too_low: String },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved { answer } => {
+                write!(f, "this part has already been solved with {answer}")
+            }
+            Rejection::Repeated { verdict } => {
+                write!(f, "this answer has already been guessed: {verdict}")
+            }
+            Rejection::AtLeast { too_high } => {
+                write!(f, "{too_high} was already too high")
+            }
+            Rejection::AtMost { too_low } => write!(f, "{too_low} was already too low"),
+        }
+    }
+}
+
+/// Every answer submitted for a day, stored as one tab separated `part verdict answer` line
+/// per guess.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl Ledger {
+    pub fn for_day(cache: &Cache, year: u16, day: u8) -> io::Result<Self> {
+        Ledger::load(cache.day_dir(year, day).join("guesses.txt"))
+    }
+
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let guesses = match fs::read_to_string(&path) {
+            Ok(content) => content.lines().filter_map(parse_guess).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Ledger { path, guesses })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// Checks whether a guess can be sent, given the verdicts of the previous guesses.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), Rejection> {
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|g| g.part == part && g.verdict.is_judged());
+
+        let number = answer.parse::<i128>().ok();
+        for guess in guesses {
+            if guess.verdict == Verdict::Correct {
+                return Err(Rejection::AlreadySolved {
+                    answer: guess.answer.clone(),
+                });
+            }
+
+            if guess.answer == answer {
+                return Err(Rejection::Repeated {
+                    verdict: guess.verdict.clone(),
+                });
+            }
+
+            let (Some(number), Ok(guessed)) = (number, guess.answer.parse::<i128>()) else {
+                continue;
+            };
+            match guess.verdict {
+                Verdict::TooHigh if number >= guessed => {
+                    return Err(Rejection::AtLeast {
+                        too_high: guess.answer.clone(),
+                    })
+                }
+                Verdict::TooLow if number <= guessed => {
+                    return Err(Rejection::AtMost {
+                        too_low: guess.answer.clone(),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}\t{}\t{answer}", part.number(), verdict.to_token())?;
+
+        self.guesses.push(Guess {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+
+        Ok(())
+    }
+}
+
+fn parse_guess(line: &str) -> Option<Guess> {
+    let mut fields = line.splitn(3, '\t');
+    let part = match fields.next()? {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+    let verdict = Verdict::from_token(fields.next()?)?;
+    let answer = fields.next()?.to_string();
+
+    Some(Guess {
+        part,
+        answer,
+        verdict,
+    })
+}
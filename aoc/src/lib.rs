@@ -0,0 +1,236 @@
+// Lets the solutions, which are included as modules of this crate, refer to it by name
+extern crate self as aoc;
+
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+pub mod answer;
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod cache;
+#[cfg(feature = "download_input")]
+pub mod client;
+pub mod days;
+pub mod generate;
+pub mod input;
+pub mod leaderboard;
+pub mod ledger;
+pub mod ocr;
+pub mod parse;
+pub mod puzzle;
+pub mod reference;
+pub mod report;
+pub mod scaffold;
+pub mod shape;
+pub mod solution;
+pub mod stream;
+pub mod visual;
+pub mod watch;
+
+pub use answer::{Answer, PixelGrid};
+use args::RunArgs;
+pub use parse::ParseError;
+pub use reference::Reference;
+pub use shape::Shape;
+pub use solution::{Day, Solution};
+pub use stream::Streaming;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Input(input::Error),
+    Parse(ParseError),
+    Stream(stream::Error),
+    /// `--stream` was given for inputs that can't be streamed, with the reason.
+    CannotStream(String),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::Input(err) => write!(f, "{err}"),
+            RunError::Parse(err) => write!(f, "{err}"),
+            RunError::Stream(err) => write!(f, "{err}"),
+            RunError::CannotStream(reason) => write!(f, "can't stream the input: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<input::Error> for RunError {
+    fn from(err: input::Error) -> Self {
+        RunError::Input(err)
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(err: ParseError) -> Self {
+        RunError::Parse(err)
+    }
+}
+
+/// Solves the parts selected by `args` for each of its inputs and prints the answers along with
+/// the time they took.
+///
+/// Without any inputs in `args` the day's input is loaded from `default_input`, or from the cache
+/// if that does not exist.
+pub fn run(year: u16, day: u8, args: &RunArgs, default_input: &Path) -> Result<(), RunError> {
+    let solution =
+        days::find(year, day).unwrap_or_else(|| panic!("{year} day {day} has not been solved"));
+    if args.stream {
+        return run_stream(solution, args, default_input);
+    }
+
+    let inputs = if args.inputs.is_empty() {
+        vec![input::load(year, day, default_input)?]
+    } else {
+        args.inputs
+            .iter()
+            .map(|path| input::read(path))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    for input in &inputs {
+        for part in args.parts() {
+            let start = Instant::now();
+            let answer = solution
+                .solve(part, &input.text)
+                .map_err(|err| err.in_file(&input.path))?;
+            let elapsed = start.elapsed();
+
+            let label = if inputs.len() > 1 {
+                format!("{year} day {day} {part} ({})", input.path.display())
+            } else {
+                format!("{year} day {day} {part}")
+            };
+            print_answer(&label, &answer, elapsed, args.quiet);
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`run`], but solves each part while reading its input, which is opened again for every
+/// part.
+fn run_stream(solution: &Day, args: &RunArgs, default_input: &Path) -> Result<(), RunError> {
+    let (year, day) = (solution.year, solution.day);
+    if !solution.can_stream() {
+        return Err(RunError::CannotStream(format!(
+            "{year} day {day} can only be solved from a whole input"
+        )));
+    }
+
+    let paths = if args.inputs.is_empty() {
+        // The input is only read as a whole when it has to be downloaded first
+        if default_input.exists() {
+            vec![default_input.to_path_buf()]
+        } else {
+            vec![input::load(year, day, default_input)?.path]
+        }
+    } else {
+        args.inputs.clone()
+    };
+    let stdin = Path::new("-");
+    if paths.iter().any(|path| path == stdin) && args.parts().len() * paths.len() > 1 {
+        return Err(RunError::CannotStream(
+            "stdin can only be read once, give a single part and input".to_string(),
+        ));
+    }
+
+    for path in &paths {
+        for part in args.parts() {
+            let (mut reader, shown): (Box<dyn BufRead>, PathBuf) = if path == stdin {
+                (Box::new(io::stdin().lock()), PathBuf::from("<stdin>"))
+            } else {
+                let file = File::open(path)
+                    .map_err(|err| RunError::Input(input::Error::Read(path.clone(), err)))?;
+                (Box::new(BufReader::new(file)), path.clone())
+            };
+
+            let start = Instant::now();
+            let answer = solution
+                .solve_stream(part, &mut reader)
+                .expect("the day can be streamed")
+                .map_err(|err| RunError::Stream(err.in_file(&shown)))?;
+            let elapsed = start.elapsed();
+
+            let label = if paths.len() > 1 {
+                format!("{year} day {day} {part} ({})", shown.display())
+            } else {
+                format!("{year} day {day} {part}")
+            };
+            print_answer(&label, &answer, elapsed, args.quiet);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_answer(label: &str, answer: &Answer, elapsed: Duration, quiet: bool) {
+    if quiet {
+        match answer.submission() {
+            Ok(submission) => println!("{submission}"),
+            Err(_) => println!("{answer}"),
+        }
+        return;
+    }
+
+    if answer.is_multiline() {
+        println!("{label} (took {elapsed:?}):\n{answer}");
+        match answer.submission() {
+            Ok(letters) => println!("which reads {letters}"),
+            Err(err) => println!("which can't be read: {err}"),
+        }
+    } else {
+        println!("{label}: {answer} (took {elapsed:?})");
+    }
+}
+
+/// Entrypoint for the per-day binaries, run from within the day directory.
+///
+/// Takes the same arguments as `aoc run`. For the Makefiles the part can also be selected with
+/// the `part` environment variable (`part1` or `part2`) and `test_file` overrides the input
+/// file, arguments on the command line take precedence.
+pub fn handle_day(year: u16, day: u8) {
+    let result = args::day_args()
+        .map_err(|err| err.to_string())
+        .and_then(|args| {
+            run(year, day, &args, Path::new("input.txt")).map_err(|err| err.to_string())
+        });
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,815 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
+
+use aoc::{
+    answers,
+    args::RunArgs,
+    bench::{self, Measurement, Short, Timings},
+    days,
+    input::{self, Input},
+    leaderboard::Leaderboard,
+    puzzle, reference, report,
+    scaffold::NewDay,
+    visual::{self, export::Format},
+    watch, Answer, Day, Part,
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Advent of code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day, both parts unless a part is given
+    Run {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        args: RunArgs,
+    },
+    /// Solve a part and submit the answer, unless earlier guesses already rule it out
+    #[cfg(feature = "download_input")]
+    Submit {
+        year: u16,
+        day: u8,
+        /// Which part to submit, 1 or 2
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this answer instead of solving the part
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Time parsing and both parts of every solved day, or of the given days
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Runs before measuring that are not counted
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        /// How many times to run each measurement
+        #[arg(short, long, default_value_t = 10)]
+        samples: usize,
+        /// Stop sampling a measurement after this many seconds
+        #[arg(long, default_value_t = 5.0)]
+        max_time: f64,
+        /// Give up on a measurement whose first run takes longer than this many seconds
+        #[arg(long, default_value_t = 60.0)]
+        timeout: f64,
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Show the change in median time compared to the results in this JSON file
+        #[arg(long)]
+        compare: Option<PathBuf>,
+    },
+    /// Run both parts of every day, or of the days of the given year, and report how they did
+    Report {
+        year: Option<u16>,
+        /// How many parts to run at the same time, defaults to the number of CPUs
+        #[arg(short, long)]
+        threads: Option<usize>,
+        /// Give up on a part after this many seconds
+        #[arg(long, default_value_t = 60.0)]
+        timeout: f64,
+    },
+    /// Run a part while recording the frames of its simulation, and show or save them
+    Visualize {
+        year: u16,
+        day: u8,
+        /// Which part to run, 1 or 2
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file to use instead of the day's input.txt, `-` reads from stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Terminal)]
+        format: Format,
+        /// The file to write to, or the directory for a file per frame
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Keep every n-th frame
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// Stop recording after this many frames
+        #[arg(long, default_value_t = 1000)]
+        limit: usize,
+        /// How many pixels wide and high a cell is in images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Frames per second of animations
+        #[arg(long, default_value_t = 20.0)]
+        fps: f64,
+    },
+    /// Create a new day from the template in dayxx, and fetch its input
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Write the example from a day's puzzle page into its test.txt, and its answers into answers.txt
+    Examples {
+        year: u16,
+        day: u8,
+        /// A saved puzzle page to read instead of the cached or downloaded one
+        #[arg(long)]
+        page: Option<PathBuf>,
+        /// Replace the example in test.txt if there already is one
+        #[arg(long)]
+        force: bool,
+    },
+    /// Write a random input for a day, as large as a real one unless a size is given
+    Generate {
+        year: u16,
+        day: u8,
+        /// About how many records the input has, what a record is depends on the day
+        #[arg(short, long)]
+        size: Option<usize>,
+        /// The same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// The file to write to instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Check a day's solution against its slow reference solvers on generated inputs
+    Compare {
+        year: u16,
+        day: u8,
+        /// How many inputs to compare on, generated from the seeds 0, 1, 2, ...
+        #[arg(long, default_value_t = 100)]
+        seeds: u64,
+        /// The size of the inputs, a tenth of a real input's by default, as the reference
+        /// solvers are slow
+        #[arg(short, long)]
+        size: Option<usize>,
+        /// Write the first input they disagree on to this file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Show the star times of a private leaderboard and how its standings changed each day
+    Leaderboard {
+        year: u16,
+        /// The id of the leaderboard, the number at the end of its url
+        id: u64,
+        /// Only show this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// A saved copy of the leaderboard's JSON to read instead of the cached or downloaded one
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// List the solved days of every year, or of the given year
+    List { year: Option<u16> },
+    /// Show the answers of a day for its example and real inputs side by side
+    Check { year: u16, day: u8 },
+    /// Run a day on each of its inputs, including the named ones in its inputs directory, and
+    /// show which of them pass
+    Inputs { year: u16, day: u8 },
+    /// Check a day again whenever its solution, inputs or known answers change
+    #[cfg(target_os = "linux")]
+    Watch { year: u16, day: u8 },
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Run { year, day, args } => {
+            let input = find_day(year, day).dir().join("input.txt");
+            aoc::run(year, day, &args, &input).unwrap_or_else(|err| fail(err));
+        }
+        #[cfg(feature = "download_input")]
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit(year, day, to_part(part), answer),
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            samples,
+            max_time,
+            timeout,
+            json,
+            compare,
+        } => {
+            let options = bench::Options {
+                warmup,
+                samples,
+                max_time: Duration::from_secs_f64(max_time),
+                timeout: Duration::from_secs_f64(timeout),
+            };
+            run_bench(year, day, &options, json, compare);
+        }
+        Command::Report {
+            year,
+            threads,
+            timeout,
+        } => {
+            let defaults = report::Options::default();
+            let options = report::Options {
+                threads: threads.unwrap_or(defaults.threads),
+                timeout: Duration::from_secs_f64(timeout),
+            };
+            run_report(year, &options);
+        }
+        Command::Visualize {
+            year,
+            day,
+            part,
+            input,
+            format,
+            output,
+            every,
+            limit,
+            scale,
+            fps,
+        } => {
+            let recording = visual::Options { every, limit };
+            let export = visual::export::Options {
+                scale: scale.max(1),
+                delay: Duration::from_secs_f64(1.0 / fps.max(0.1)),
+            };
+            visualize(
+                year,
+                day,
+                to_part(part),
+                input,
+                recording,
+                format,
+                output,
+                &export,
+            );
+        }
+        Command::New { year, day } => new_day(year, day),
+        Command::Examples {
+            year,
+            day,
+            page,
+            force,
+        } => examples(year, day, page, force),
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+            output,
+        } => generate(year, day, size, seed, output),
+        Command::Compare {
+            year,
+            day,
+            seeds,
+            size,
+            output,
+        } => compare(year, day, seeds, size, output),
+        Command::Leaderboard {
+            year,
+            id,
+            day,
+            json,
+        } => leaderboard(year, id, day, json),
+        Command::List { year } => {
+            let years = match year {
+                Some(year) => vec![year],
+                None => days::years(),
+            };
+            for year in years {
+                let solved: Vec<String> = days::in_year(year).map(|d| d.day.to_string()).collect();
+                if solved.is_empty() {
+                    fail(format!("no days of {year} have been solved"));
+                }
+                println!("{year}: {}", solved.join(", "));
+            }
+        }
+        Command::Check { year, day } => check(year, day),
+        Command::Inputs { year, day } => inputs(year, day),
+        #[cfg(target_os = "linux")]
+        Command::Watch { year, day } => watch(year, day),
+    }
+}
+
+fn generate(year: u16, day: u8, size: Option<usize>, seed: u64, output: Option<PathBuf>) {
+    let generator = aoc::generate::find(year, day)
+        .unwrap_or_else(|| fail(format!("there is no generator for {year} day {day}")));
+    let size = size.unwrap_or(generator.size);
+
+    let written = match &output {
+        Some(path) => fs::File::create(path).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            generator.write(seed, size, &mut out)?;
+            out.flush()
+        }),
+        None => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            generator
+                .write(seed, size, &mut out)
+                .and_then(|_| out.flush())
+        }
+    };
+    if let Err(err) = written {
+        let to = output.map_or("stdout".to_string(), |path| path.display().to_string());
+        fail(format!("failed to write to {to}: {err}"));
+    }
+}
+
+fn compare(year: u16, day: u8, seeds: u64, size: Option<usize>, output: Option<PathBuf>) {
+    let solution = find_day(year, day);
+    if !solution.has_reference() {
+        fail(format!("{year} day {day} has no reference solver"));
+    }
+    let generator = aoc::generate::find(year, day)
+        .unwrap_or_else(|| fail(format!("there is no generator for {year} day {day}")));
+    let size = size.unwrap_or(generator.size / 10).max(1);
+
+    // Both solvers may panic on inputs they can't handle, which is reported as a disagreement
+    std::panic::set_hook(Box::new(|_| {}));
+
+    match reference::compare(solution, generator, 0..seeds, size) {
+        Ok(compared) => println!("{year} day {day}: agrees on all {compared} inputs"),
+        Err(disagreement) => {
+            if let Some(path) = output {
+                fs::write(&path, &disagreement.input).unwrap_or_else(|err| {
+                    fail(format!("failed to write {}: {err}", path.display()))
+                });
+            }
+            fail(format!("{year} day {day}: {disagreement}"));
+        }
+    }
+}
+
+fn leaderboard(year: u16, id: u64, day: Option<u8>, json: Option<PathBuf>) {
+    let json = match json {
+        Some(path) => fs::read_to_string(&path)
+            .unwrap_or_else(|err| fail(format!("failed to read {}: {err}", path.display()))),
+        None => fetch_leaderboard(year, id),
+    };
+    let leaderboard = Leaderboard::parse(&json)
+        .unwrap_or_else(|err| fail(format!("failed to read the leaderboard: {err}")));
+    if leaderboard.event != year {
+        fail(format!(
+            "the leaderboard is of {}, not {year}",
+            leaderboard.event
+        ));
+    }
+
+    print!("{}", leaderboard.report(day));
+}
+
+#[cfg(feature = "download_input")]
+fn fetch_leaderboard(year: u16, id: u64) -> String {
+    use aoc::{cache::Cache, client::Client};
+
+    let client = Client::from_env().unwrap_or_else(|err| fail(err));
+    client
+        .cached_leaderboard(&Cache::from_env(), year, id)
+        .unwrap_or_else(|err| fail(err))
+}
+
+#[cfg(not(feature = "download_input"))]
+fn fetch_leaderboard(_year: u16, _id: u64) -> String {
+    fail("downloading is disabled, pass a saved copy of the leaderboard with --json")
+}
+
+#[cfg(feature = "download_input")]
+fn submit(year: u16, day: u8, part: Part, answer: Option<String>) {
+    use aoc::{cache::Cache, client::Client, ledger::Ledger};
+
+    let answer = answer.unwrap_or_else(|| {
+        let solution = find_day(year, day);
+        let input = load_input(year, day, None);
+        let answer = solution
+            .solve(part, &input.text)
+            .unwrap_or_else(|err| fail(err.in_file(&input.path)));
+
+        answer.submission().unwrap_or_else(|err| {
+            fail(format!(
+                "{err}, read the answer and pass it with --answer:\n{answer}"
+            ))
+        })
+    });
+
+    let mut ledger = Ledger::for_day(&Cache::from_env(), year, day)
+        .unwrap_or_else(|err| fail(format!("failed to read guesses: {err}")));
+    if let Err(rejection) = ledger.check(part, &answer) {
+        fail(format!("not submitting {answer}: {rejection}"));
+    }
+
+    let client = Client::from_env().unwrap_or_else(|err| fail(err));
+    let verdict = client
+        .submit(year, day, part, &answer)
+        .unwrap_or_else(|err| fail(err));
+
+    ledger
+        .record(part, &answer, verdict.clone())
+        .unwrap_or_else(|err| fail(format!("failed to record guess: {err}")));
+
+    println!("{year} day {day} {part}: {answer}: {verdict}");
+}
+
+fn run_bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    options: &bench::Options,
+    json: Option<PathBuf>,
+    compare: Option<PathBuf>,
+) {
+    let baseline: Vec<Timings> = match compare {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|err| err.to_string()))
+            .unwrap_or_else(|err| fail(format!("failed to read {}: {err}", path.display()))),
+        None => Vec::new(),
+    };
+
+    let selected: Vec<&Day> = days::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .collect();
+    if selected.is_empty() {
+        fail("no solved days match");
+    }
+
+    // Unfinished parts panic on `todo!()`, which would clutter the table
+    std::panic::set_hook(Box::new(|_| {}));
+
+    println!(
+        "{:<12} {:>10} {:>10} {:>10}",
+        "day", "parse", "part 1", "part 2"
+    );
+    let mut results = Vec::new();
+    for solution in selected {
+        let input = match input::load(
+            solution.year,
+            solution.day,
+            &solution.dir().join("input.txt"),
+        ) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("skipping {} day {}: {err}", solution.year, solution.day);
+                continue;
+            }
+        };
+
+        let timings = match solution.bench(&input.text, options) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!(
+                    "skipping {} day {}: {}",
+                    solution.year,
+                    solution.day,
+                    err.in_file(&input.path)
+                );
+                continue;
+            }
+        };
+        let before = baseline
+            .iter()
+            .find(|t| t.year == timings.year && t.day == timings.day);
+
+        println!(
+            "{:<12} {:>10} {:>10} {:>10}",
+            format!("{} day {}", timings.year, timings.day),
+            cell(&timings.parse),
+            cell(&timings.part_one),
+            cell(&timings.part_two),
+        );
+        if let Some(before) = before {
+            println!(
+                "{:<12} {:>10} {:>10} {:>10}",
+                "",
+                change(&before.parse, &timings.parse),
+                change(&before.part_one, &timings.part_one),
+                change(&before.part_two, &timings.part_two),
+            );
+        }
+
+        results.push(timings);
+    }
+
+    if let Some(path) = json {
+        let content = serde_json::to_string_pretty(&results).expect("timings are serializable");
+        fs::write(&path, content)
+            .unwrap_or_else(|err| fail(format!("failed to write {}: {err}", path.display())));
+    }
+}
+
+/// The median time of a measurement, `-` for a part that panicked.
+fn cell(measurement: &Measurement) -> String {
+    match measurement {
+        Measurement::Sampled(stats) => Short(stats.median()).to_string(),
+        Measurement::Panicked => "-".to_string(),
+        Measurement::TimedOut => "timeout".to_string(),
+    }
+}
+
+fn change(before: &Measurement, after: &Measurement) -> String {
+    match (before.stats(), after.stats()) {
+        (Some(before), Some(after)) if before.median_ns > 0 => {
+            let ratio = after.median_ns as f64 / before.median_ns as f64;
+            format!("{:+.1}%", (ratio - 1.0) * 100.0)
+        }
+        _ => String::new(),
+    }
+}
+
+fn run_report(year: Option<u16>, options: &report::Options) {
+    let selected: Vec<&Day> = days::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y))
+        .collect();
+    if selected.is_empty() {
+        fail("no solved days match");
+    }
+
+    // Unfinished parts panic on `todo!()`, which would clutter the report
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let report = report::run(&selected, Path::new("."), options);
+
+    let answers: Vec<String> = report
+        .runs
+        .iter()
+        .map(|run| {
+            run.answer
+                .as_ref()
+                .map(Answer::one_line)
+                .unwrap_or_default()
+        })
+        .collect();
+    let width = answers.iter().map(|a| a.chars().count()).max().unwrap_or(0);
+    for (run, answer) in report.runs.iter().zip(answers) {
+        let elapsed = run
+            .elapsed
+            .map(|e| Short(e).to_string())
+            .unwrap_or_default();
+        let line = format!(
+            "{:<12} {} {:>10}  {answer:<width$}  {}",
+            format!("{} day {}", run.year, run.day),
+            run.part,
+            elapsed,
+            run.status
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let counts: Vec<String> = report
+        .counts()
+        .into_iter()
+        .map(|(kind, count)| format!("{count} {kind}"))
+        .collect();
+    println!(
+        "\n{} parts in {}: {}",
+        report.runs.len(),
+        Short(report.total),
+        counts.join(", ")
+    );
+    if report.has_failures() {
+        process::exit(1);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn visualize(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+    recording: visual::Options,
+    format: Format,
+    output: Option<PathBuf>,
+    options: &visual::export::Options,
+) {
+    if format.needs_output() && output.is_none() {
+        fail(visual::export::Error::NoOutput(format));
+    }
+    let solution = find_day(year, day);
+    let input = load_input(year, day, input);
+
+    let (answer, frames) = visual::recording(recording, || solution.try_solve(part, &input.text));
+    let answer = answer.unwrap_or_else(|err| match err {
+        aoc::solution::Error::Parse(err) => fail(err.in_file(&input.path)),
+        err => fail(err),
+    });
+    if frames.is_empty() {
+        fail(format!(
+            "{year} day {day} {part} does not record any frames"
+        ));
+    }
+
+    visual::export::save(&frames, format, output.as_deref(), options)
+        .unwrap_or_else(|err| fail(err));
+    // The answer would be drawn over by the animation, or mixed into the text on stdout
+    eprintln!(
+        "{year} day {day} {part}: {answer} ({} frames)",
+        frames.len()
+    );
+}
+
+fn check(year: u16, day: u8) {
+    let solution = find_day(year, day);
+    let dir = solution.dir();
+
+    // Unfinished parts panic on `todo!()`, which would clutter the table
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let table = watch::Table::run(solution, &dir).unwrap_or_else(|err| {
+        fail(format!(
+            "failed to read the inputs in {}: {err}",
+            dir.display()
+        ))
+    });
+    print!("{table}");
+    if table.has_failures() {
+        process::exit(1);
+    }
+}
+
+fn inputs(year: u16, day: u8) {
+    let solution = find_day(year, day);
+    let dir = solution.dir();
+
+    // Unfinished parts panic on `todo!()`, which is shown as skipped
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let checks = answers::check_inputs(solution, &dir).unwrap_or_else(|err| {
+        fail(format!(
+            "failed to read the inputs in {}: {err}",
+            dir.display()
+        ))
+    });
+    for check in &checks {
+        print!("{check}");
+    }
+
+    let passed = checks.iter().filter(|c| c.passes()).count();
+    let failed = checks.iter().filter(|c| c.is_failure()).count();
+    println!(
+        "\n{passed} of {} inputs passed, {failed} failed",
+        checks.len()
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Checks the day in a new process each time, so changes to the solution are compiled in and
+/// compile errors are shown in place of the answers.
+#[cfg(target_os = "linux")]
+fn watch(year: u16, day: u8) {
+    let dir = find_day(year, day).dir();
+    // Started before the first check, so changes made while it runs are not missed
+    let mut watcher = watch::Watcher::new(&dir)
+        .unwrap_or_else(|err| fail(format!("failed to watch {}: {err}", dir.display())));
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut changed = Vec::new();
+    loop {
+        // Clears the screen and moves the cursor to the top left
+        print!("\x1b[2J\x1b[H");
+        print!("{year} day {day}, watching {}", dir.display());
+        if !changed.is_empty() {
+            let files: Vec<String> = changed
+                .iter()
+                .map(|f: &PathBuf| f.display().to_string())
+                .collect();
+            print!(" ({} changed)", files.join(", "));
+        }
+        println!("\n");
+
+        let status = process::Command::new(&cargo)
+            .args([
+                "run",
+                "--release",
+                "--quiet",
+                "--package",
+                "aoc",
+                "--",
+                "check",
+            ])
+            .args([year.to_string(), day.to_string()])
+            .status();
+        if let Err(err) = status {
+            fail(format!("failed to run cargo: {err}"));
+        }
+
+        changed = watcher
+            .wait()
+            .unwrap_or_else(|err| fail(format!("failed to watch {}: {err}", dir.display())));
+    }
+}
+
+fn examples(year: u16, day: u8, page: Option<PathBuf>, overwrite: bool) {
+    let dir = find_day(year, day).dir();
+    let html = match page {
+        Some(path) => fs::read_to_string(&path)
+            .unwrap_or_else(|err| fail(format!("failed to read {}: {err}", path.display()))),
+        None => puzzle_page(year, day).unwrap_or_else(|err| fail(err)),
+    };
+
+    write_example(&html, &dir, overwrite).unwrap_or_else(|err| fail(err));
+}
+
+/// The puzzle page from the cache, downloading it with the `download_input` feature.
+fn puzzle_page(year: u16, day: u8) -> Result<String, String> {
+    let cache = aoc::cache::Cache::from_env();
+
+    #[cfg(feature = "download_input")]
+    {
+        aoc::client::Client::from_env()
+            .and_then(|client| client.cached_puzzle(&cache, year, day))
+            .map_err(|err| format!("failed to download the puzzle: {err}"))
+    }
+
+    #[cfg(not(feature = "download_input"))]
+    {
+        let path = cache.puzzle_path(year, day);
+        fs::read_to_string(&path).map_err(|err| {
+            format!(
+                "failed to read {}, pass a saved page with --page: {err}",
+                path.display()
+            )
+        })
+    }
+}
+
+fn write_example(html: &str, dir: &Path, overwrite: bool) -> Result<(), puzzle::Error> {
+    let example = puzzle::Page::parse(html).example()?;
+    let added = example.write(dir, overwrite)?;
+
+    println!("wrote the example into {}", dir.join("test.txt").display());
+    for part in added {
+        let answer = example.answers[usize::from(part.number()) - 1]
+            .as_deref()
+            .unwrap_or_default();
+        println!("added {answer} as the answer to {part} of the example");
+    }
+    Ok(())
+}
+
+fn new_day(year: u16, day: u8) {
+    let dir = NewDay { year, day }
+        .create(Path::new("."))
+        .unwrap_or_else(|err| fail(err));
+    println!("created {}", dir.display());
+
+    #[cfg(feature = "download_input")]
+    {
+        use aoc::{cache::Cache, client::Client};
+
+        // The day is usable without its input, so failing to fetch it is not an error
+        let input = Client::from_env()
+            .and_then(|client| client.cached_input(&Cache::from_env(), year, day));
+        match input {
+            Ok(input) => {
+                let path = dir.join("input.txt");
+                fs::write(&path, input).unwrap_or_else(|err| {
+                    fail(format!("failed to write {}: {err}", path.display()))
+                });
+                println!("fetched the input into {}", path.display());
+            }
+            Err(err) => eprintln!("warning: could not fetch the input: {err}"),
+        }
+
+        let example = puzzle_page(year, day)
+            .and_then(|html| write_example(&html, &dir, false).map_err(|err| err.to_string()));
+        if let Err(err) = example {
+            eprintln!("warning: could not fill in the example: {err}");
+        }
+    }
+}
+
+fn to_part(part: u8) -> Part {
+    if part == 1 {
+        Part::One
+    } else {
+        Part::Two
+    }
+}
+
+fn find_day(year: u16, day: u8) -> &'static Day {
+    days::find(year, day).unwrap_or_else(|| fail(format!("{year} day {day} has not been solved")))
+}
+
+fn load_input(year: u16, day: u8, path: Option<PathBuf>) -> Input {
+    let input = match path {
+        Some(path) => input::read(&path),
+        None => input::load(year, day, &find_day(year, day).dir().join("input.txt")),
+    };
+
+    input.unwrap_or_else(|err| fail(err))
+}
+
+fn fail(err: impl Display) -> ! {
+    eprintln!("error: {err}");
+    process::exit(1);
+}
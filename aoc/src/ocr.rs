@@ -0,0 +1,130 @@
+//! Reads the capital letters that some puzzles draw on a screen of pixels.
+//!
+//! The letters use the font the puzzles always draw with: 4 pixels wide and 6 high, with one
+//! dark column between letters. Only the letters that have been seen in puzzles are known,
+//! anything else is reported instead of guessed.
+
+use std::{fmt::Display, ops::Range};
+
+use crate::PixelGrid;
+
+const HEIGHT: usize = 6;
+const WIDTH: usize = 4;
+/// A letter and the dark column after it.
+const STRIDE: usize = WIDTH + 1;
+
+const FONT: [(char, [&str; HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The screen is not as high as the letters.
+    Height(usize),
+    /// Glyphs that are not letters of the font.
+    Unrecognized(Vec<Unrecognized>),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Height(height) => write!(
+                f,
+                "letters are {HEIGHT} pixels high but the screen is {height} pixels high"
+            ),
+            Error::Unrecognized(glyphs) => {
+                write!(f, "unrecognized ")?;
+                for (i, glyph) in glyphs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{glyph}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A glyph that could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unrecognized {
+    /// Which glyph it is, counted from 0.
+    pub index: usize,
+    /// The columns of the screen it covers.
+    pub columns: Range<usize>,
+}
+
+impl Display for Unrecognized {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "glyph {} (columns {}-{})",
+            self.index + 1,
+            self.columns.start,
+            self.columns.end - 1
+        )
+    }
+}
+
+/// Reads the letters on the screen, failing if any glyph is not a known letter.
+pub fn read(screen: &PixelGrid) -> Result<String, Error> {
+    if screen.height() != HEIGHT {
+        return Err(Error::Height(screen.height()));
+    }
+
+    let mut letters = String::new();
+    let mut unrecognized = Vec::new();
+    for (index, x) in (0..screen.width()).step_by(STRIDE).enumerate() {
+        let columns = x..(x + STRIDE).min(screen.width());
+        match glyph_at(screen, columns.clone()) {
+            Some(letter) => letters.push(letter),
+            None => unrecognized.push(Unrecognized { index, columns }),
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(letters)
+    } else {
+        Err(Error::Unrecognized(unrecognized))
+    }
+}
+
+fn glyph_at(screen: &PixelGrid, columns: Range<usize>) -> Option<char> {
+    if columns.len() < WIDTH {
+        return None;
+    }
+
+    // The column between letters has to be dark, or this is something wider than a letter
+    if columns.len() > WIDTH && (0..HEIGHT).any(|y| screen.get(columns.start + WIDTH, y)) {
+        return None;
+    }
+
+    FONT.iter()
+        .find(|(_, rows)| {
+            rows.iter().enumerate().all(|(y, row)| {
+                row.bytes()
+                    .enumerate()
+                    .all(|(dx, pixel)| screen.get(columns.start + dx, y) == (pixel == b'#'))
+            })
+        })
+        .map(|(letter, _)| *letter)
+}
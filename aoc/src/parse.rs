@@ -0,0 +1,180 @@
+//! Errors for malformed input, pointing at the line and column where parsing stopped.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aoc_utils::{grid, Grid, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file the input was read from, set by the runner with [`ParseError::in_file`].
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// What the parser was looking for, e.g. `` `,` `` or `a number`.
+    pub expected: String,
+    /// The line the error is on, without its line ending.
+    pub source_line: String,
+    /// The column `source_line` starts at, which is 1 unless it is an excerpt of a line that was
+    /// too long to keep, see [`ParseError::excerpt`].
+    pub source_column: usize,
+}
+
+impl ParseError {
+    /// Creates an error pointing at the start of `at`, which must be a slice of `input`.
+    ///
+    /// A slice from somewhere else points at the end of the input.
+    pub fn new(input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let source_line = input[line_start..]
+            .split('\n')
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('\r');
+
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            source_line: source_line.to_string(),
+            source_column: 1,
+        }
+    }
+
+    /// An error at a line and column of an input that is not kept in memory, with `excerpt` being
+    /// the part of the line that starts at the column.
+    pub fn excerpt(
+        line: usize,
+        column: usize,
+        excerpt: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            source_line: excerpt.into(),
+            source_column: column,
+        }
+    }
+
+    pub fn in_file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_path_buf());
+        self
+    }
+
+    /// What was found where the expected token should have been.
+    pub fn found(&self) -> String {
+        match self
+            .source_line
+            .chars()
+            .nth(self.column - self.source_column)
+        {
+            Some(c) => format!("`{c}`"),
+            None => "end of line".to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        };
+        writeln!(
+            f,
+            "expected {} at {location}, found {}",
+            self.expected,
+            self.found()
+        )?;
+
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        writeln!(f, "{margin} |")?;
+        // An excerpt is marked as not starting at the start of the line
+        let elided = if self.source_column > 1 { "..." } else { "" };
+        writeln!(f, "{number} | {elided}{}", self.source_line)?;
+        write!(
+            f,
+            "{margin} | {}^ expected {}",
+            " ".repeat(elided.len() + self.column - self.source_column),
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Helpers for taking apart slices of an input, returning errors that point into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Parser { input }
+    }
+
+    /// An error pointing at the start of `at`.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.input, at, expected)
+    }
+
+    /// An error pointing just past the end of `at`, for when something is missing after it.
+    pub fn error_after(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&at[at.len()..], expected)
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error_after(s, format!("`{}`", delimiter.escape_debug())))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("`{}`", prefix.escape_debug())))
+    }
+
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix)
+            .ok_or_else(|| self.error_after(s, format!("`{}`", suffix.escape_debug())))
+    }
+
+    pub fn number<T: FromStr>(&self, s: &str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, "a number"))
+    }
+
+    /// Parses the whole input as a grid with one cell per character, see [`Grid::parse`].
+    /// `cells` names what the cells are for the error when a line has the wrong width, e.g.
+    /// `trees`.
+    pub fn grid<T>(
+        &self,
+        cells: &str,
+        cell: impl FnMut(&'a str, Point<usize>) -> Result<T, ParseError>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse(self.input, cell).map_err(|err| match err {
+            grid::Error::Width { at, width } => self.error(at, format!("{width} {cells}")),
+            grid::Error::Cell(err) => err,
+        })
+    }
+}
@@ -0,0 +1,193 @@
+//! Reads the example of a puzzle from the HTML of its page, so it does not have to be copied into
+//! `test.txt` by hand.
+//!
+//! Each part's description is an `<article class="day-desc">`. The example input is the first
+//! `<pre><code>` block of part 1, and the answer to the example is the last highlighted code
+//! (`<code><em>`) of the description of each part, which is where the puzzles state it.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::Answers, Part};
+
+/// The name of a cached puzzle page, in the day's directory of the [`Cache`](crate::cache::Cache).
+pub const PAGE_FILE: &str = "puzzle.html";
+
+#[derive(Debug)]
+pub enum Error {
+    /// The page does not contain the description of any part.
+    NoDescription,
+    /// The description of part 1 has no `<pre><code>` block.
+    NoExample,
+    /// The day already has a `test.txt` that is not empty.
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoDescription => write!(f, "the page does not contain a puzzle description"),
+            Error::NoExample => write!(f, "the puzzle description has no example input"),
+            Error::Exists(path) => write!(f, "{} already has an example", path.display()),
+            Error::Io(path, err) => write!(f, "failed to access {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The descriptions of the parts on a puzzle page, which only has part 2 once part 1 is solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<'a> {
+    parts: Vec<&'a str>,
+}
+
+impl<'a> Page<'a> {
+    pub fn parse(html: &'a str) -> Self {
+        Page {
+            parts: between(html, "<article class=\"day-desc\">", "</article>").collect(),
+        }
+    }
+
+    /// Whether the page has the description of part 2.
+    pub fn is_complete(&self) -> bool {
+        self.parts.len() >= 2
+    }
+
+    /// The `<pre><code>` blocks of a part's description, as text.
+    pub fn code_blocks(&self, part: Part) -> Vec<String> {
+        self.description(part)
+            .map(|d| {
+                between(d, "<pre><code>", "</code></pre>")
+                    .map(text)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The last highlighted code of a part's description, which is the answer to the example.
+    pub fn example_answer(&self, part: Part) -> Option<String> {
+        let highlighted = between(self.description(part)?, "<code><em>", "</em></code>");
+        highlighted.last().map(text)
+    }
+
+    pub fn example(&self) -> Result<Example, Error> {
+        if self.parts.is_empty() {
+            return Err(Error::NoDescription);
+        }
+
+        let input = self
+            .code_blocks(Part::One)
+            .into_iter()
+            .next()
+            .ok_or(Error::NoExample)?;
+        Ok(Example {
+            input,
+            answers: [
+                self.example_answer(Part::One),
+                self.example_answer(Part::Two),
+            ],
+        })
+    }
+
+    fn description(&self, part: Part) -> Option<&'a str> {
+        self.parts.get(usize::from(part.number()) - 1).copied()
+    }
+}
+
+/// The example input of a puzzle along with the answers to it that are on the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Example {
+    /// Writes the example into the day's `test.txt` and adds its answers to `answers.txt`.
+    ///
+    /// A `test.txt` that is not empty is only replaced with `overwrite`, which also drops the
+    /// answers to the example it had. Otherwise answers that are already known are kept. Returns
+    /// the parts whose answers were added.
+    pub fn write(&self, dir: &Path, overwrite: bool) -> Result<Vec<Part>, Error> {
+        let test = dir.join("test.txt");
+        let existing = read(&test)?;
+        let replaced = !existing.trim().is_empty() && existing != self.input;
+        if replaced && !overwrite {
+            return Err(Error::Exists(test));
+        }
+
+        let answers_path = dir.join("answers.txt");
+        let mut content = read(&answers_path)?;
+        if replaced {
+            content = content
+                .lines()
+                .filter(|l| l.split_whitespace().next() != Some("test.txt"))
+                .map(|l| format!("{l}\n"))
+                .collect();
+        }
+        let known = Answers::parse(&content);
+
+        let mut added = Vec::new();
+        for (part, answer) in [Part::One, Part::Two].into_iter().zip(&self.answers) {
+            let Some(answer) = answer else { continue };
+            if known.get("test.txt", part).is_some() {
+                continue;
+            }
+
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push_str(&format!("test.txt {} {answer}\n", part.number()));
+            added.push(part);
+        }
+
+        write(&test, &self.input)?;
+        write(&answers_path, &content)?;
+        Ok(added)
+    }
+}
+
+/// Reads a file, an empty string if it does not exist.
+fn read(path: &Path) -> Result<String, Error> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(Error::Io(path.to_path_buf(), err)),
+    }
+}
+
+fn write(path: &Path, content: &str) -> Result<(), Error> {
+    fs::write(path, content).map_err(|err| Error::Io(path.to_path_buf(), err))
+}
+
+/// The parts of `html` between each `start` and the following `end`.
+fn between<'a>(html: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let (_, after) = rest.split_once(start)?;
+        let (inside, after) = after.split_once(end)?;
+        rest = after;
+        Some(inside)
+    })
+}
+
+/// The text of some HTML, without its tags and with the entities the pages use decoded.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(i) = rest.find('<') {
+        text.push_str(&rest[..i]);
+        rest = rest[i..].split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,99 @@
+//! Slow but obviously correct solvers next to the optimized ones, for checking that a faster
+//! algorithm still gives the same answers before it replaces a simpler one.
+//!
+//! A day implements [`Reference`] next to its [`Solution`] for the parts that have a reference
+//! solver, and is registered with [`Day::with_reference`]. [`compare`] then runs both solvers on
+//! generated inputs and stops at the first input they give different answers for, or that
+//! either of them fails on.
+
+use std::fmt::Display;
+
+use crate::{generate::Generator, solution::Error, Answer, Day, Part, Solution};
+
+/// A [`Solution`] with a reference solver for one or both parts.
+pub trait Reference: Solution {
+    /// Solves part 1 the slow way, `None` if part 1 has no reference solver.
+    fn reference_part_one(_input: Self::Parsed<'_>) -> Option<Answer> {
+        None
+    }
+
+    /// Solves part 2 the slow way, `None` if part 2 has no reference solver.
+    fn reference_part_two(_input: Self::Parsed<'_>) -> Option<Answer> {
+        None
+    }
+}
+
+/// A generated input on which the optimized and the reference solver of a part disagree.
+#[derive(Debug, Clone)]
+pub struct Disagreement {
+    pub part: Part,
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub optimized: Result<Answer, Error>,
+    pub reference: Result<Answer, Error>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |result: &Result<Answer, Error>| match result {
+            Ok(answer) => answer.one_line(),
+            Err(err) => err.to_string(),
+        };
+        write!(
+            f,
+            "{} differs on the input with seed {} and size {}: the solution gives {}, the reference {}",
+            self.part,
+            self.seed,
+            self.size,
+            show(&self.optimized),
+            show(&self.reference),
+        )
+    }
+}
+
+impl std::error::Error for Disagreement {}
+
+/// Solves the parts of `day` that have a reference solver both ways on inputs of `size`
+/// generated from each of the seeds, and returns the first input they disagree on.
+///
+/// Both solvers have to give the same answer, so an input that either of them fails on is a
+/// disagreement too, even when both do. A part whose solution is not implemented yet
+/// (`todo!()`) is skipped. Returns how many inputs at least one part was compared on.
+pub fn compare(
+    day: &Day,
+    generator: &Generator,
+    seeds: impl IntoIterator<Item = u64>,
+    size: usize,
+) -> Result<usize, Box<Disagreement>> {
+    let mut compared = 0;
+    for seed in seeds {
+        let input = generator.generate(seed, size);
+        let mut any_part = false;
+        for part in [Part::One, Part::Two] {
+            let Some(reference) = day.try_solve_reference(part, &input) else {
+                continue;
+            };
+            let optimized = day.try_solve(part, &input);
+            if matches!(&optimized, Err(Error::Panic(panic)) if panic.is_todo()) {
+                continue;
+            }
+            any_part = true;
+            if matches!((&optimized, &reference), (Ok(o), Ok(r)) if o == r) {
+                continue;
+            }
+            return Err(Box::new(Disagreement {
+                part,
+                seed,
+                size,
+                input,
+                optimized,
+                reference,
+            }));
+        }
+        if any_part {
+            compared += 1;
+        }
+    }
+    Ok(compared)
+}
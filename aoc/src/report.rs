@@ -0,0 +1,251 @@
+//! Runs both parts of many days on their inputs at once and collects the results, for checking
+//! that every day still works.
+
+use std::{
+    fmt::Display,
+    path::Path,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{self, Answers, Outcome},
+    input, Answer, Day, ParseError, Part,
+};
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// How many parts are run at the same time.
+    pub threads: usize,
+    /// Give up on a part that takes longer than this.
+    pub timeout: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Status {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// There is no known answer to compare with.
+    Unknown,
+    /// The part is not implemented yet.
+    Todo,
+    Panicked(String),
+    /// The input could not be parsed.
+    Invalid(ParseError),
+    /// The part did not finish within [`Options::timeout`].
+    TimedOut,
+    /// The input could not be read, or downloaded.
+    NoInput(String),
+}
+
+impl Status {
+    pub const KINDS: [&'static str; 8] = [
+        "correct",
+        "wrong",
+        "unknown",
+        "todo",
+        "panicked",
+        "invalid",
+        "timed out",
+        "no input",
+    ];
+
+    /// What kind of status it is, without the details, one of [`Status::KINDS`].
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Wrong { .. } => "wrong",
+            Status::Unknown => "unknown",
+            Status::Todo => "todo",
+            Status::Panicked(_) => "panicked",
+            Status::Invalid(_) => "invalid",
+            Status::TimedOut => "timed out",
+            Status::NoInput(_) => "no input",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Wrong { .. } | Status::Panicked(_) | Status::Invalid(_) | Status::TimedOut
+        )
+    }
+}
+
+impl From<Outcome> for Status {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Correct => Status::Correct,
+            Outcome::Wrong { expected, .. } => Status::Wrong { expected },
+            Outcome::Unknown => Status::Unknown,
+            Outcome::Todo => Status::Todo,
+            Outcome::Panicked(message) => Status::Panicked(message),
+            Outcome::Invalid(err) => Status::Invalid(err),
+            Outcome::NoInput(err) => Status::NoInput(err),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Correct => write!(f, "ok"),
+            Status::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Status::Unknown => write!(f, "no known answer"),
+            Status::Todo => write!(f, "todo"),
+            Status::Panicked(message) => write!(f, "panicked: {message}"),
+            Status::Invalid(err) => write!(f, "invalid input: {err}"),
+            Status::TimedOut => write!(f, "timed out"),
+            Status::NoInput(err) => write!(f, "no input: {err}"),
+        }
+    }
+}
+
+/// The result of running a part of a day on its input.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The answer, if the part finished with one.
+    pub answer: Option<Answer>,
+    /// How long parsing and the part took, if it finished.
+    pub elapsed: Option<Duration>,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// The runs in the order of the days and parts.
+    pub runs: Vec<Run>,
+    /// How long the whole run took.
+    pub total: Duration,
+}
+
+impl Report {
+    pub fn has_failures(&self) -> bool {
+        self.runs.iter().any(|run| run.status.is_failure())
+    }
+
+    /// How many runs there are of each [`Status::kind`], for the kinds that occurred.
+    pub fn counts(&self) -> Vec<(&'static str, usize)> {
+        Status::KINDS
+            .into_iter()
+            .map(|kind| {
+                let count = self.runs.iter().filter(|r| r.status.kind() == kind).count();
+                (kind, count)
+            })
+            .filter(|&(_, count)| count > 0)
+            .collect()
+    }
+}
+
+/// Runs both parts of each day on the `input.txt` in its directory under `root`, falling back to
+/// the cache like [`input::load`], and compares the answers with its `answers.txt`.
+///
+/// A part that panics or times out only fails that part. A part that times out is left running
+/// in the background, as threads can't be stopped.
+pub fn run(days: &[&'static Day], root: &Path, options: &Options) -> Report {
+    let start = Instant::now();
+
+    let mut jobs = Vec::new();
+    let mut runs = Vec::new();
+    for &day in days {
+        let dir = root.join(day.dir());
+        let known = Answers::load(&dir.join("answers.txt")).unwrap_or_default();
+        let input = input::load(day.year, day.day, &dir.join("input.txt"))
+            .map(|input| Arc::<str>::from(input.text))
+            .map_err(|err| err.to_string());
+
+        for part in [Part::One, Part::Two] {
+            let expected = known.get("input.txt", part).map(str::to_string);
+            match &input {
+                Ok(input) => jobs.push((day, part, input.clone(), expected)),
+                Err(err) => runs.push(Run {
+                    year: day.year,
+                    day: day.day,
+                    part,
+                    answer: None,
+                    elapsed: None,
+                    status: Status::NoInput(err.clone()),
+                }),
+            }
+        }
+    }
+
+    let jobs = Mutex::new(jobs.into_iter());
+    let finished = Mutex::new(runs);
+    thread::scope(|s| {
+        for _ in 0..options.threads.max(1) {
+            s.spawn(|| loop {
+                let Some((day, part, input, expected)) = jobs.lock().unwrap().next() else {
+                    break;
+                };
+                let run = run_part(day, part, input, expected, options.timeout);
+                finished.lock().unwrap().push(run);
+            });
+        }
+    });
+
+    let mut runs = finished.into_inner().unwrap();
+    runs.sort_by_key(|run| (run.year, run.day, run.part.number()));
+    Report {
+        runs,
+        total: start.elapsed(),
+    }
+}
+
+/// Solves the part on a thread of its own, so that it can be abandoned if it times out.
+fn run_part(
+    day: &'static Day,
+    part: Part,
+    input: Arc<str>,
+    expected: Option<String>,
+    timeout: Duration,
+) -> Run {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let result = day.try_solve(part, &input);
+        let _ = sender.send((result, start.elapsed()));
+    });
+
+    let (answer, elapsed, status) = match receiver.recv_timeout(timeout) {
+        Ok((result, elapsed)) => {
+            let answer = result.as_ref().ok().cloned();
+            let status = answers::outcome(result, expected.as_deref()).into();
+            (answer, Some(elapsed), status)
+        }
+        Err(RecvTimeoutError::Timeout) => (None, None, Status::TimedOut),
+        // Panics are caught by `try_solve`, so this should not happen
+        Err(RecvTimeoutError::Disconnected) => (
+            None,
+            None,
+            Status::Panicked("the thread solving it stopped".to_string()),
+        ),
+    };
+
+    Run {
+        year: day.year,
+        day: day.day,
+        part,
+        answer,
+        elapsed,
+        status,
+    }
+}
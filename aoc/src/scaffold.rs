@@ -0,0 +1,237 @@
+//! Creates a new day from the template in `dayxx` and registers it with the runner.
+//!
+//! The template files contain `{{name}}` (e.g. `day05`), `{{dir}}` (`2022/day05`), `{{package}}`
+//! (`day_2022_05`), `{{type}}` (`Day05`), `{{year}}` and `{{day}}`, which are filled in for the
+//! new day.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The files of a day crate, relative to its directory.
+const DAY_FILES: [(&str, &str); 10] = [
+    ("Cargo.toml", include_str!("../../dayxx/Cargo.toml")),
+    ("Dockerfile", include_str!("../../dayxx/Dockerfile")),
+    ("Makefile", include_str!("../../dayxx/Makefile")),
+    ("README.md", include_str!("../../dayxx/README.md")),
+    ("answers.txt", include_str!("../../dayxx/answers.txt")),
+    (".gitignore", include_str!("../../dayxx/.gitignore")),
+    (".dockerignore", include_str!("../../dayxx/.dockerignore")),
+    ("src/main.rs", include_str!("../../dayxx/src/main.rs")),
+    ("src/lib.rs", include_str!("../../dayxx/src/lib.rs")),
+    (
+        "src/solution.rs",
+        include_str!("../../dayxx/src/solution.rs"),
+    ),
+];
+
+/// The file in the year's module in `aoc/src/days` that implements [`Solution`](crate::Solution) for the day.
+const REGISTRY_FILE: &str = include_str!("../../dayxx/registry.rs");
+
+#[derive(Debug)]
+pub enum Error {
+    Exists(PathBuf),
+    /// A file the day has to be registered in does not look like expected.
+    CannotRegister(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Exists(path) => write!(f, "{} already exists", path.display()),
+            Error::CannotRegister(path) => write!(
+                f,
+                "could not find where to register the day in {}",
+                path.display()
+            ),
+            Error::Io(path, err) => write!(f, "failed to write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The names a day goes by, which the templates are filled in with.
+#[derive(Debug, Clone)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl NewDay {
+    /// The name of the directory and the crate, e.g. `day05`.
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    /// The day's directory relative to the root of the repository, e.g. `2022/day05`.
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(self.year.to_string()).join(self.name())
+    }
+
+    /// The name of the crate, which has to be unique across years, e.g. `day_2022_05`.
+    pub fn package(&self) -> String {
+        format!("day_{}_{:02}", self.year, self.day)
+    }
+
+    /// The name of the year's module in `aoc/src/days`, e.g. `year2022`.
+    pub fn module(&self) -> String {
+        format!("year{}", self.year)
+    }
+
+    /// The name of the type implementing [`Solution`](crate::Solution), e.g. `Day05`.
+    pub fn type_name(&self) -> String {
+        format!("Day{:02}", self.day)
+    }
+
+    fn fill_in(&self, template: &str) -> String {
+        template
+            .replace("{{name}}", &self.name())
+            .replace("{{dir}}", &self.dir().to_string_lossy())
+            .replace("{{package}}", &self.package())
+            .replace("{{type}}", &self.type_name())
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{day}}", &self.day.to_string())
+    }
+
+    /// Creates the day's directory in the repository at `root`, and registers it in the
+    /// workspace and the runner's list of days, adding a module for the year if it is the first
+    /// day of that year.
+    ///
+    /// Returns the day's directory. Nothing is written if the day already exists.
+    pub fn create(&self, root: &Path) -> Result<PathBuf, Error> {
+        let dir = root.join(self.dir());
+        let year_dir = root.join("aoc/src/days").join(self.module());
+        let registry = year_dir.join(format!("{}.rs", self.name()));
+        for path in [&dir, &registry] {
+            if path.exists() {
+                return Err(Error::Exists(path.clone()));
+            }
+        }
+
+        // Work out every edit before writing anything, so a failure leaves no half-made day
+        let manifest = root.join("Cargo.toml");
+        let manifest_content = insert_sorted(
+            &read(&manifest)?,
+            &format!("    \"{}\",", self.dir().to_string_lossy()),
+            "    \"NN/dayNN\",",
+        )
+        .ok_or_else(|| Error::CannotRegister(manifest.clone()))?;
+
+        let day_entries = [
+            (format!("mod {};", self.name()), "mod dayNN;"),
+            (
+                format!("pub use {}::{};", self.name(), self.type_name()),
+                "pub use dayNN::DayNN;",
+            ),
+        ];
+        let year = year_dir.join("mod.rs");
+        let year_content = if year.exists() {
+            let mut content = read(&year)?;
+            for (line, entry) in day_entries {
+                content = insert_sorted(&content, &line, entry)
+                    .ok_or_else(|| Error::CannotRegister(year.clone()))?;
+            }
+            content
+        } else {
+            let [(module, _), (export, _)] = day_entries;
+            format!("//! The days of {}.\n\n{module}\n\n{export}\n", self.year)
+        };
+
+        let days = root.join("aoc/src/days/mod.rs");
+        let mut days_content = read(&days)?;
+        if !year.exists() {
+            days_content = insert_sorted(
+                &days_content,
+                &format!("pub mod {};", self.module()),
+                "pub mod yearNN;",
+            )
+            .ok_or_else(|| Error::CannotRegister(days.clone()))?;
+        }
+        days_content = insert_sorted(
+            &days_content,
+            &format!("    Day::of::<{}::{}>(),", self.module(), self.type_name()),
+            "    Day::*::<yearNN::DayNN>(),",
+        )
+        .ok_or_else(|| Error::CannotRegister(days.clone()))?;
+
+        for (file, template) in DAY_FILES {
+            write(&dir.join(file), &self.fill_in(template))?;
+        }
+        write(&dir.join("test.txt"), "")?;
+        write(&registry, &self.fill_in(REGISTRY_FILE))?;
+        write(&year, &year_content)?;
+        write(&manifest, &manifest_content)?;
+        write(&days, &days_content)?;
+
+        Ok(dir)
+    }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| Error::Io(dir.to_path_buf(), err))?;
+    }
+    fs::write(path, content).map_err(|err| Error::Io(path.to_path_buf(), err))
+}
+
+/// The line with every number replaced by `NN`.
+fn normalize(line: &str) -> String {
+    let mut normalized = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            while chars.next_if(char::is_ascii_digit).is_some() {}
+            normalized.push_str("NN");
+        } else {
+            normalized.push(c);
+        }
+    }
+    normalized
+}
+
+/// What a line matching `entry` is sorted by, `None` if it does not match. In `entry`, `NN`
+/// stands for a number and `*` for a name, such as the constructor in `Day::*::<yearNN::DayNN>()`.
+/// Lines are sorted by what comes after the name, so the name does not change their order.
+fn sort_key<'a>(line: &'a str, entry: &str) -> Option<&'a str> {
+    let Some((before, after)) = entry.split_once('*') else {
+        return (normalize(line) == entry).then_some(line);
+    };
+    let rest = line.strip_prefix(before)?;
+    let name = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    let rest = &rest[name..];
+    (name > 0 && normalize(rest) == after).then_some(rest)
+}
+
+/// Inserts `new_line` into the sorted lines matching `entry` (see [`sort_key`]), `None` if there
+/// are no such lines or the line is already there.
+fn insert_sorted(content: &str, new_line: &str, entry: &str) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let key = sort_key(new_line, entry)?;
+    let entries: Vec<(usize, &str)> = (0..lines.len())
+        .filter_map(|i| Some((i, sort_key(lines[i], entry)?)))
+        .collect();
+    if entries.iter().any(|&(_, k)| k == key) {
+        return None;
+    }
+
+    let position = entries
+        .iter()
+        .find(|&&(_, k)| k > key)
+        .map(|&(i, _)| i)
+        .or_else(|| entries.last().map(|(i, _)| i + 1))?;
+    lines.insert(position, new_line);
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    Some(content)
+}
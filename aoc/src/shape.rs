@@ -0,0 +1,114 @@
+//! Cleaning up inputs before they are parsed, and checking that they are laid out the way a day
+//! expects.
+//!
+//! Inputs saved from a browser or edited on another system can have CRLF line endings, trailing
+//! spaces or a missing final newline, which the parsers should not have to deal with. The runner
+//! [normalizes](normalize) every input, and then checks it against the [`Shape`] the day declares
+//! with [`Solution::SHAPE`](crate::Solution::SHAPE), so that a wrong layout is reported as such
+//! instead of as whatever the parser trips over first.
+
+use std::borrow::Cow;
+
+use crate::parse::{ParseError, Parser};
+
+/// How an input is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// Nothing is checked, the parser takes care of everything.
+    Any,
+    /// One record per line, without blank lines.
+    Lines,
+    /// A rectangular grid of characters, with every line as wide as the first.
+    Grid,
+    /// Blocks of lines separated by a single blank line, each with exactly `lines` lines if
+    /// given.
+    Blocks { lines: Option<usize> },
+}
+
+impl Shape {
+    /// Checks a [normalized](normalize) input, pointing at the first line that does not fit.
+    pub fn check(&self, input: &str) -> Result<(), ParseError> {
+        let p = Parser::new(input);
+        if *self != Shape::Any && input.is_empty() {
+            return Err(p.error(input, "an input, the file is empty"));
+        }
+
+        match *self {
+            Shape::Any => {}
+            Shape::Lines => {
+                if let Some(blank) = input.lines().find(|l| l.is_empty()) {
+                    return Err(p.error(blank, "a record on every line"));
+                }
+            }
+            Shape::Grid => {
+                let width = input.lines().next().map_or(0, |l| l.chars().count());
+                for line in input.lines() {
+                    let expected = || format!("a row of {width} characters");
+                    match line.char_indices().nth(width) {
+                        Some((i, _)) => return Err(p.error(&line[i..], expected())),
+                        None if line.chars().count() < width => {
+                            return Err(p.error_after(line, expected()))
+                        }
+                        None => {}
+                    }
+                }
+            }
+            Shape::Blocks { lines } => {
+                let expected = || match lines {
+                    Some(1) => "blocks of 1 line".to_string(),
+                    Some(n) => format!("blocks of {n} lines"),
+                    None => "a single blank line between blocks".to_string(),
+                };
+
+                // How many lines of the current block have been seen, and its last line
+                let mut block = (0, "");
+                for line in input.lines() {
+                    match (line.is_empty(), block.0) {
+                        (true, 0) => return Err(p.error(line, expected())),
+                        (true, seen) if lines.is_some_and(|n| seen < n) => {
+                            return Err(p.error(line, expected()))
+                        }
+                        (true, _) => block = (0, line),
+                        (false, seen) if lines.is_some_and(|n| seen == n) => {
+                            return Err(p.error(line, "a blank line between blocks"))
+                        }
+                        (false, seen) => block = (seen + 1, line),
+                    }
+                }
+
+                if lines.is_some_and(|n| block.0 < n) {
+                    return Err(p.error_after(block.1, expected()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Turns CRLF line endings into LF, removes a byte order mark, trailing whitespace on each line
+/// and blank lines at the end, and ends the input with a single newline.
+///
+/// Leading whitespace is kept, as some inputs draw with it. Inputs that are already normalized
+/// are not copied.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let trimmed = input.trim_end();
+    let is_normal = input.len() == trimmed.len() + 1
+        && input.ends_with('\n')
+        && !input.contains('\r')
+        && trimmed.lines().all(|l| l.len() == l.trim_end().len());
+    if is_normal || input.is_empty() {
+        return Cow::Borrowed(input);
+    }
+    if trimmed.is_empty() {
+        return Cow::Borrowed("");
+    }
+
+    let mut normalized = String::with_capacity(trimmed.len() + 1);
+    for line in trimmed.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
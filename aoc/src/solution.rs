@@ -0,0 +1,231 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    fmt::Display,
+    io::BufRead,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+};
+
+use crate::{
+    bench,
+    parse::ParseError,
+    reference::Reference,
+    shape::{self, Shape},
+    stream::{self, Streaming},
+    Answer, Part,
+};
+
+/// A solved day.
+///
+/// The parsed input is consumed by the part that solves it, so each part gets its own parse.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// How the input is laid out, which is checked before it is parsed.
+    const SHAPE: Shape = Shape::Any;
+
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+    fn part_one(input: Self::Parsed<'_>) -> Answer;
+    fn part_two(input: Self::Parsed<'_>) -> Answer;
+}
+
+/// A type-erased [`Solution`], as listed in the registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<(), ParseError>,
+    solve: fn(Part, &str) -> Result<Answer, ParseError>,
+    bench: fn(&str, &bench::Options) -> Result<bench::Timings, ParseError>,
+    stream: Option<StreamFn>,
+    reference: Option<ReferenceFn>,
+}
+
+type StreamFn = fn(Part, &mut dyn BufRead) -> Result<Answer, stream::Error>;
+type ReferenceFn = fn(Part, &str) -> Result<Option<Answer>, ParseError>;
+
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            parse: parse::<S>,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+            stream: None,
+            reference: None,
+        }
+    }
+
+    /// A day that can also be solved from a reader, see [`stream`].
+    pub const fn streaming<S: Streaming + 'static>() -> Self {
+        Day {
+            stream: Some(solve_stream::<S>),
+            ..Day::of::<S>()
+        }
+    }
+
+    /// A day with reference solvers to check the solution against, see
+    /// [`reference`](crate::reference).
+    pub const fn with_reference<S: Reference + 'static>() -> Self {
+        Day {
+            reference: Some(solve_reference::<S>),
+            ..Day::of::<S>()
+        }
+    }
+
+    /// The day's directory, relative to the root of the repository.
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(self.year.to_string()).join(format!("day{:02}", self.day))
+    }
+
+    /// Only parses the input, for checking that the parser copes with it.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Parses the input and solves the given part.
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        (self.solve)(part, input)
+    }
+
+    pub fn can_stream(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Solves the given part while reading the input, `None` if the day can't be solved that
+    /// way.
+    pub fn solve_stream(
+        &self,
+        part: Part,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<Answer, stream::Error>> {
+        self.stream.map(|stream| stream(part, input))
+    }
+
+    pub fn has_reference(&self) -> bool {
+        self.reference.is_some()
+    }
+
+    /// Like [`Day::try_solve`], but with the reference solver of the part, `None` if the part
+    /// has none.
+    pub fn try_solve_reference(&self, part: Part, input: &str) -> Option<Result<Answer, Error>> {
+        let reference = self.reference?;
+        match panic::catch_unwind(AssertUnwindSafe(|| reference(part, input))) {
+            Ok(Ok(answer)) => answer.map(Ok),
+            Ok(Err(err)) => Some(Err(Error::Parse(err))),
+            Err(payload) => Some(Err(Error::Panic(Panic::from(payload)))),
+        }
+    }
+
+    /// Times parsing and both parts separately, see [`bench`].
+    pub fn bench(
+        &self,
+        input: &str,
+        options: &bench::Options,
+    ) -> Result<bench::Timings, ParseError> {
+        (self.bench)(input, options)
+    }
+
+    /// Like [`Day::solve`], but catches panics, such as the `todo!()` in an unfinished part.
+    pub fn try_solve(&self, part: Part, input: &str) -> Result<Answer, Error> {
+        panic::catch_unwind(AssertUnwindSafe(|| self.solve(part, input)))
+            .map_err(|payload| Error::Panic(Panic::from(payload)))?
+            .map_err(Error::Parse)
+    }
+}
+
+/// Why [`Day::try_solve`] did not produce an answer.
+#[derive(Debug, Clone)]
+pub enum Error {
+    Parse(ParseError),
+    Panic(Panic),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Panic(panic) => write!(f, "{panic}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A panic caught while solving.
+#[derive(Debug, Clone)]
+pub struct Panic {
+    pub message: String,
+}
+
+impl Panic {
+    /// Whether the panic came from a `todo!()`.
+    pub fn is_todo(&self) -> bool {
+        self.message.starts_with("not yet implemented")
+    }
+}
+
+impl From<Box<dyn Any + Send>> for Panic {
+    fn from(payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+
+        Panic { message }
+    }
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "panicked: {}", self.message)
+    }
+}
+
+/// Normalizes the input and checks it against the day's [`Solution::SHAPE`], so that it is ready
+/// to be parsed.
+pub(crate) fn prepare<S: Solution>(input: &str) -> Result<Cow<'_, str>, ParseError> {
+    let input = shape::normalize(input);
+    S::SHAPE.check(&input)?;
+    Ok(input)
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(&prepare::<S>(input)?).map(drop)
+}
+
+fn solve<S: Solution>(part: Part, input: &str) -> Result<Answer, ParseError> {
+    let input = prepare::<S>(input)?;
+    let parsed = S::parse(&input)?;
+    Ok(match part {
+        Part::One => S::part_one(parsed),
+        Part::Two => S::part_two(parsed),
+    })
+}
+
+fn solve_stream<S: Streaming>(
+    part: Part,
+    input: &mut dyn BufRead,
+) -> Result<Answer, stream::Error> {
+    match part {
+        Part::One => S::stream_part_one(input),
+        Part::Two => S::stream_part_two(input),
+    }
+}
+
+fn solve_reference<S: Reference>(part: Part, input: &str) -> Result<Option<Answer>, ParseError> {
+    let input = prepare::<S>(input)?;
+    let parsed = S::parse(&input)?;
+    Ok(match part {
+        Part::One => S::reference_part_one(parsed),
+        Part::Two => S::reference_part_two(parsed),
+    })
+}
@@ -0,0 +1,278 @@
+//! Solving days straight from a reader, a record at a time, for generated inputs that are too
+//! large to be read into memory.
+//!
+//! Days whose parts look at each record once implement [`Streaming`] next to their
+//! [`Solution`], and are registered with [`Day::streaming`](crate::Day::streaming). A streamed
+//! input is cleaned up as it is read, the way [`shape::normalize`](crate::shape::normalize)
+//! cleans up a whole input, but it is not checked against the day's shape up front: errors are
+//! only found once the parser gets to them, and a part that has its answer before the end of the
+//! input does not read the rest.
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    thread,
+};
+
+use crate::{
+    parse::{ParseError, Parser},
+    Answer, Solution,
+};
+
+/// A [`Solution`] that can also be solved from a reader, in constant memory.
+pub trait Streaming: Solution {
+    fn stream_part_one<R: BufRead>(input: R) -> Result<Answer, Error>;
+    fn stream_part_two<R: BufRead>(input: R) -> Result<Answer, Error>;
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Error {
+    /// Sets the file of a parse error, see [`ParseError::in_file`].
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(err.in_file(path)),
+            err => err,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "failed to read the input: {err}"),
+            Error::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The records of an input with a record per line, each parsed as it is read.
+///
+/// The iterator ends early at the first line that can't be read or parsed, which is then
+/// returned by [`Records::solve`]. Blank lines at the end of the input are left out.
+pub struct Records<R, F> {
+    reader: R,
+    parse: F,
+    /// The last line that was read, without its line ending and trailing whitespace.
+    line: String,
+    /// The number of the last line that was read.
+    number: usize,
+    /// Whether `line` still has to be parsed.
+    held: bool,
+    /// Blank lines before `line` that still have to be parsed, they are only known not to be at
+    /// the end of the input once a line that is not blank follows.
+    blank: usize,
+    error: Option<Error>,
+}
+
+impl<R, F, T> Records<R, F>
+where
+    R: BufRead,
+    F: for<'a> FnMut(&Parser<'a>, &'a str) -> Result<T, ParseError>,
+{
+    /// Parses each line with `parse`, which gets a [`Parser`] for the line. The errors it
+    /// returns are moved to the line's number in the input.
+    pub fn new(reader: R, parse: F) -> Self {
+        Records {
+            reader,
+            parse,
+            line: String::new(),
+            number: 0,
+            held: false,
+            blank: 0,
+            error: None,
+        }
+    }
+
+    /// Solves a part from the records, the error that ended them early takes the place of the
+    /// answer. The part may panic when the records end early, that panic is then not passed on.
+    pub fn solve(mut self, solve: impl FnOnce(&mut Self) -> Answer) -> Result<Answer, Error> {
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| solve(&mut self)));
+        finish(answer, self.error)
+    }
+
+    fn parse_line(&mut self, number: usize, blank: bool) -> Option<T> {
+        let line = if blank { "" } else { self.line.as_str() };
+        match (self.parse)(&Parser::new(line), line) {
+            Ok(record) => Some(record),
+            Err(mut err) => {
+                err.line = number;
+                self.error = Some(Error::Parse(err));
+                None
+            }
+        }
+    }
+}
+
+impl<R, F, T> Iterator for Records<R, F>
+where
+    R: BufRead,
+    F: for<'a> FnMut(&Parser<'a>, &'a str) -> Result<T, ParseError>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if self.error.is_some() {
+                return None;
+            }
+
+            if self.held {
+                if self.blank > 0 {
+                    let number = self.number - self.blank;
+                    self.blank -= 1;
+                    return self.parse_line(number, true);
+                }
+                self.held = false;
+                return self.parse_line(self.number, false);
+            }
+
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => {
+                    self.error = Some(Error::Io(err));
+                    return None;
+                }
+            }
+            self.number += 1;
+
+            if self.number == 1 && self.line.starts_with('\u{feff}') {
+                self.line.drain(..'\u{feff}'.len_utf8());
+            }
+            let len = self.line.trim_end().len();
+            self.line.truncate(len);
+
+            if self.line.is_empty() {
+                self.blank += 1;
+            } else {
+                self.held = true;
+            }
+        }
+    }
+}
+
+/// The bytes of an input that is a single long line, up to the first whitespace.
+///
+/// Each byte is checked with `valid` as it is read, and the iterator ends early at the first one
+/// that is not, or when the input can't be read. That error is then returned by [`Bytes::solve`].
+pub struct Bytes<R, F> {
+    reader: R,
+    valid: F,
+    /// What a valid byte is, for the error.
+    expected: &'static str,
+    /// The column of the last byte that was read.
+    column: usize,
+    done: bool,
+    error: Option<Error>,
+}
+
+impl<R: BufRead, F: FnMut(u8) -> bool> Bytes<R, F> {
+    /// How much of the line after an invalid byte is shown in the error.
+    const EXCERPT: usize = 20;
+
+    pub fn new(reader: R, valid: F, expected: &'static str) -> Self {
+        Bytes {
+            reader,
+            valid,
+            expected,
+            column: 0,
+            done: false,
+            error: None,
+        }
+    }
+
+    /// Solves a part from the bytes, like [`Records::solve`].
+    pub fn solve(mut self, solve: impl FnOnce(&mut Self) -> Answer) -> Result<Answer, Error> {
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| solve(&mut self)));
+        finish(answer, self.error)
+    }
+}
+
+impl<R: BufRead, F: FnMut(u8) -> bool> Iterator for Bytes<R, F> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.done {
+            return None;
+        }
+
+        let mut buffer = match self.reader.fill_buf() {
+            Ok(buffer) => buffer,
+            Err(err) => {
+                self.done = true;
+                self.error = Some(Error::Io(err));
+                return None;
+            }
+        };
+        if self.column == 0 && buffer.starts_with("\u{feff}".as_bytes()) {
+            self.reader.consume(3);
+            buffer = match self.reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(err) => {
+                    self.done = true;
+                    self.error = Some(Error::Io(err));
+                    return None;
+                }
+            };
+        }
+
+        let byte = match buffer.first() {
+            Some(byte) if !byte.is_ascii_whitespace() => *byte,
+            _ => {
+                self.done = true;
+                return None;
+            }
+        };
+        self.column += 1;
+
+        if !(self.valid)(byte) {
+            let end = buffer
+                .iter()
+                .take(Self::EXCERPT)
+                .position(u8::is_ascii_whitespace)
+                .unwrap_or(buffer.len().min(Self::EXCERPT));
+            let excerpt = String::from_utf8_lossy(&buffer[..end]);
+            self.done = true;
+            self.error = Some(Error::Parse(ParseError::excerpt(
+                1,
+                self.column,
+                excerpt,
+                self.expected,
+            )));
+            return None;
+        }
+
+        self.reader.consume(1);
+        Some(byte)
+    }
+}
+
+/// The answer of a part, unless the input it was solved from ended early because of an error.
+fn finish(answer: thread::Result<Answer>, error: Option<Error>) -> Result<Answer, Error> {
+    if let Some(err) = error {
+        return Err(err);
+    }
+    answer.or_else(|payload| panic::resume_unwind(payload))
+}
@@ -0,0 +1,345 @@
+//! Turns recorded frames into a terminal animation, text, or images.
+//!
+//! Frames are first lined up on a canvas that covers all of them, using their origins, so an
+//! animation does not jump around when the area a simulation covers grows.
+
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use aoc_utils::{Grid, Point};
+use clap::ValueEnum;
+
+use super::{Cell, Color, Frame};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Play the frames in the terminal
+    Terminal,
+    /// All frames as text, one after another
+    Text,
+    /// A PPM image per frame
+    Ppm,
+    /// A PNG image per frame
+    Png,
+    /// An animated GIF
+    Gif,
+}
+
+impl Format {
+    /// Whether the output is a directory with a file per frame.
+    pub fn is_sequence(self) -> bool {
+        matches!(self, Format::Ppm | Format::Png)
+    }
+
+    /// Whether the output can't go to stdout.
+    pub fn needs_output(self) -> bool {
+        self.is_sequence() || self == Format::Gif
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// How many pixels wide and high a cell is in images.
+    pub scale: usize,
+    /// How long each frame is shown in animations.
+    pub delay: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            scale: 4,
+            delay: Duration::from_millis(50),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    NoFrames,
+    /// The format needs a file or directory to write to.
+    NoOutput(Format),
+    TooLarge {
+        width: usize,
+        height: usize,
+    },
+    Io(PathBuf, io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoFrames => write!(f, "no frames were recorded"),
+            Error::NoOutput(format) => write!(
+                f,
+                "{} output has to be written to a {}, pass it with --output",
+                format
+                    .to_possible_value()
+                    .expect("no skipped formats")
+                    .get_name(),
+                if format.is_sequence() {
+                    "directory"
+                } else {
+                    "file"
+                }
+            ),
+            Error::TooLarge { width, height } => write!(
+                f,
+                "a {width}x{height} image is too large for a gif, try a smaller --scale"
+            ),
+            Error::Io(path, err) => write!(f, "failed to write {}: {err}", path.display()),
+            Error::Png(err) => write!(f, "failed to encode png: {err}"),
+            Error::Gif(err) => write!(f, "failed to encode gif: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Lines the frames up on a canvas large enough for all of them, filling what a frame does not
+/// cover with [`Cell::BLANK`].
+pub fn canvas(frames: &[Frame]) -> Vec<Grid<Cell>> {
+    let Some(first) = frames.first() else {
+        return Vec::new();
+    };
+
+    let end = |frame: &Frame| {
+        frame.origin + Point::new(frame.cells.width() as i64, frame.cells.height() as i64)
+    };
+    let (mut min, mut max) = (first.origin, end(first));
+    for frame in frames {
+        let frame_end = end(frame);
+        min = Point::new(min.x.min(frame.origin.x), min.y.min(frame.origin.y));
+        max = Point::new(max.x.max(frame_end.x), max.y.max(frame_end.y));
+    }
+
+    let size = max - min;
+    frames
+        .iter()
+        .map(|frame| {
+            let offset = frame.origin - min;
+            Grid::from_fn(size.x as usize, size.y as usize, |p| {
+                let x = (p.x as i64 - offset.x).try_into();
+                let y = (p.y as i64 - offset.y).try_into();
+                match (x, y) {
+                    (Ok(x), Ok(y)) => frame.cells.get(Point::new(x, y)).copied(),
+                    _ => None,
+                }
+                .unwrap_or(Cell::BLANK)
+            })
+        })
+        .collect()
+}
+
+/// Every frame as text, each under a `frame <n>/<count>` heading.
+pub fn text(frames: &[Frame]) -> String {
+    let canvas = canvas(frames);
+    let mut text = String::new();
+    for (i, frame) in canvas.iter().enumerate() {
+        text.push_str(&format!(
+            "frame {}/{}\n{}\n\n",
+            i + 1,
+            canvas.len(),
+            frame.display_with(|cell| cell.glyph)
+        ));
+    }
+    text
+}
+
+/// Plays the frames in a terminal that understands ANSI escape codes and 24-bit colors.
+pub fn play(frames: &[Frame], delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    // Clear the screen once and then draw every frame over the previous one
+    write!(out, "\x1b[?25l\x1b[2J")?;
+    for frame in canvas(frames) {
+        write!(out, "\x1b[H")?;
+        for row in frame.rows() {
+            let mut color = None;
+            for cell in row {
+                if color != Some(cell.color) {
+                    let Color { r, g, b } = cell.color;
+                    write!(out, "\x1b[38;2;{r};{g};{b}m")?;
+                    color = Some(cell.color);
+                }
+                write!(out, "{}", cell.glyph)?;
+            }
+            writeln!(out, "\x1b[0m")?;
+        }
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    write!(out, "\x1b[?25h")?;
+    out.flush()
+}
+
+/// The colors of the pixels row by row, three bytes each, with each cell `scale` pixels wide
+/// and high.
+fn pixels(frame: &Grid<Cell>, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * scale * scale * 3);
+    for row in frame.rows() {
+        for _ in 0..scale {
+            for cell in row {
+                for _ in 0..scale {
+                    pixels.extend([cell.color.r, cell.color.g, cell.color.b]);
+                }
+            }
+        }
+    }
+    pixels
+}
+
+/// A binary PPM (P6) image of the frame.
+pub fn ppm(frame: &Grid<Cell>, scale: usize) -> Vec<u8> {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        frame.width() * scale,
+        frame.height() * scale
+    )
+    .into_bytes();
+    image.extend(pixels(frame, scale));
+    image
+}
+
+pub fn png(frame: &Grid<Cell>, scale: usize) -> Result<Vec<u8>, Error> {
+    let mut image = Vec::new();
+    let mut encoder = png::Encoder::new(
+        &mut image,
+        (frame.width() * scale) as u32,
+        (frame.height() * scale) as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(Error::Png)?;
+    writer
+        .write_image_data(&pixels(frame, scale))
+        .map_err(Error::Png)?;
+    writer.finish().map_err(Error::Png)?;
+    Ok(image)
+}
+
+/// An animated GIF of the frames that loops forever.
+pub fn gif(frames: &[Frame], options: &Options) -> Result<Vec<u8>, Error> {
+    let canvas = canvas(frames);
+    let Some(first) = canvas.first() else {
+        return Err(Error::NoFrames);
+    };
+    let (width, height) = (
+        first.width() * options.scale,
+        first.height() * options.scale,
+    );
+    let too_large = || Error::TooLarge { width, height };
+    let gif_width = u16::try_from(width).map_err(|_| too_large())?;
+    let gif_height = u16::try_from(height).map_err(|_| too_large())?;
+
+    // Frames rarely use many colors, so a palette shared by all of them usually fits
+    let mut palette: Vec<Color> = Vec::new();
+    for cell in canvas
+        .iter()
+        .flat_map(|frame| frame.iter().map(|(_, cell)| cell))
+    {
+        if !palette.contains(&cell.color) {
+            palette.push(cell.color);
+            if palette.len() > 256 {
+                break;
+            }
+        }
+    }
+    let shared = palette.len() <= 256;
+    let palette_bytes: Vec<u8> = if shared {
+        palette.iter().flat_map(|c| [c.r, c.g, c.b]).collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut image = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut image, gif_width, gif_height, &palette_bytes)
+            .map_err(Error::Gif)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(Error::Gif)?;
+        let delay = (options.delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+
+        for frame in &canvas {
+            let mut gif_frame = if shared {
+                let scaled = frame.map(|cell| {
+                    palette
+                        .iter()
+                        .position(|&c| c == cell.color)
+                        .expect("every color is in the palette") as u8
+                });
+                let mut indices = Vec::with_capacity(width * height);
+                for row in scaled.rows() {
+                    for _ in 0..options.scale {
+                        for &index in row {
+                            indices.extend(std::iter::repeat_n(index, options.scale));
+                        }
+                    }
+                }
+                gif::Frame {
+                    width: gif_width,
+                    height: gif_height,
+                    buffer: Cow::Owned(indices),
+                    ..gif::Frame::default()
+                }
+            } else {
+                gif::Frame::from_rgb_speed(gif_width, gif_height, &pixels(frame, options.scale), 10)
+            };
+            gif_frame.delay = delay;
+            encoder.write_frame(&gif_frame).map_err(Error::Gif)?;
+        }
+    }
+
+    Ok(image)
+}
+
+/// Shows or saves the frames in the given format. Terminal and text output go to stdout unless
+/// `output` is given, image sequences are written as `frame_0001.<ext>`, ... into the `output`
+/// directory.
+pub fn save(
+    frames: &[Frame],
+    format: Format,
+    output: Option<&Path>,
+    options: &Options,
+) -> Result<(), Error> {
+    if frames.is_empty() {
+        return Err(Error::NoFrames);
+    }
+
+    let write = |path: &Path, content: &[u8]| {
+        fs::write(path, content).map_err(|err| Error::Io(path.to_path_buf(), err))
+    };
+    let stdout = |err| Error::Io(PathBuf::from("<stdout>"), err);
+
+    match (format, output) {
+        (Format::Terminal, _) => {
+            play(frames, options.delay, &mut io::stdout().lock()).map_err(stdout)
+        }
+        (Format::Text, None) => io::stdout()
+            .write_all(text(frames).as_bytes())
+            .map_err(stdout),
+        (Format::Text, Some(path)) => write(path, text(frames).as_bytes()),
+        (Format::Gif, Some(path)) => write(path, &gif(frames, options)?),
+        (Format::Ppm | Format::Png, Some(dir)) => {
+            fs::create_dir_all(dir).map_err(|err| Error::Io(dir.to_path_buf(), err))?;
+            for (i, frame) in canvas(frames).iter().enumerate() {
+                let (extension, image) = match format {
+                    Format::Ppm => ("ppm", ppm(frame, options.scale)),
+                    _ => ("png", png(frame, options.scale)?),
+                };
+                write(&dir.join(format!("frame_{:04}.{extension}", i + 1)), &image)?;
+            }
+            Ok(())
+        }
+        (format, None) => Err(Error::NoOutput(format)),
+    }
+}
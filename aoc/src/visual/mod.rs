@@ -0,0 +1,196 @@
+//! Frames recorded from the simulations some days run, for debugging and write-ups.
+//!
+//! A solution calls [`record`] with a closure that draws the current state. Nothing is drawn
+//! unless the part is being run inside [`recording`], so the calls can stay in the solutions
+//! without slowing them down. The frames are turned into animations and images by [`export`].
+
+pub mod export;
+
+use std::{cell::RefCell, fmt::Display};
+
+use aoc_utils::{Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(133, 153, 0);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(231, 189, 0);
+    pub const SAND: Color = Color::rgb(194, 160, 90);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+
+    /// A shade between black and white, `0` being black and `255` white.
+    pub const fn gray(level: u8) -> Self {
+        Color::rgb(level, level, level)
+    }
+}
+
+/// A character for text output and a color for images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    /// Where nothing was drawn.
+    pub const BLANK: Cell = Cell::new(' ', Color::BLACK);
+
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Cell { glyph, color }
+    }
+}
+
+/// A picture of the state of a simulation. `origin` is where its top left cell is, so frames
+/// that cover different parts of the simulation can be lined up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub origin: Point<i64>,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Frame {
+            origin: Point::default(),
+            cells,
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, cell: impl Fn(&T) -> Cell) -> Self {
+        Frame::new(grid.map(cell))
+    }
+
+    /// A frame just large enough for the given cells, with `background` everywhere else. A cell
+    /// given later is drawn over an earlier one at the same point.
+    pub fn from_points(
+        points: impl IntoIterator<Item = (Point<i64>, Cell)>,
+        background: Cell,
+    ) -> Self {
+        let points: Vec<(Point<i64>, Cell)> = points.into_iter().collect();
+        let Some(first) = points.first() else {
+            return Frame::new(Grid::new(0, 0, background));
+        };
+
+        let (mut min, mut max) = (first.0, first.0);
+        for (point, _) in &points {
+            min = Point::new(min.x.min(point.x), min.y.min(point.y));
+            max = Point::new(max.x.max(point.x), max.y.max(point.y));
+        }
+
+        let size = max - min;
+        let mut cells = Grid::new(size.x as usize + 1, size.y as usize + 1, background);
+        for (point, cell) in points {
+            let offset = point - min;
+            cells[Point::new(offset.x as usize, offset.y as usize)] = cell;
+        }
+
+        Frame { origin: min, cells }
+    }
+
+    /// Moves the frame so its top left cell is at `origin`.
+    pub fn at(self, origin: Point<i64>) -> Self {
+        Frame { origin, ..self }
+    }
+}
+
+/// The glyphs of the cells, one line per row.
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells.display_with(|cell| cell.glyph))
+    }
+}
+
+/// Which of the frames a simulation records are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Keep every n-th frame, for simulations with many small steps.
+    pub every: usize,
+    /// Stop keeping frames after this many.
+    pub limit: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            every: 1,
+            limit: 1000,
+        }
+    }
+}
+
+struct Recorder {
+    options: Options,
+    calls: usize,
+    frames: Vec<Frame>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Whether frames are being recorded on this thread, for simulations that have to keep extra
+/// state around to draw their frames.
+pub fn is_recording() -> bool {
+    RECORDER.with(|recorder| recorder.borrow().is_some())
+}
+
+/// Records a frame if [`recording`], `frame` is only called if the frame is kept.
+pub fn record(frame: impl FnOnce() -> Frame) {
+    RECORDER.with(|recorder| {
+        let mut recorder = recorder.borrow_mut();
+        let Some(recorder) = recorder.as_mut() else {
+            return;
+        };
+
+        let keep = recorder.calls % recorder.options.every.max(1) == 0
+            && recorder.frames.len() < recorder.options.limit;
+        recorder.calls += 1;
+        if keep {
+            recorder.frames.push(frame());
+        }
+    });
+}
+
+/// Runs `f` while recording the frames passed to [`record`] on this thread.
+pub fn recording<R>(options: Options, f: impl FnOnce() -> R) -> (R, Vec<Frame>) {
+    // Stops recording even if `f` panics
+    struct Stop;
+    impl Drop for Stop {
+        fn drop(&mut self) {
+            RECORDER.with(|recorder| recorder.borrow_mut().take());
+        }
+    }
+
+    RECORDER.with(|recorder| {
+        *recorder.borrow_mut() = Some(Recorder {
+            options,
+            calls: 0,
+            frames: Vec::new(),
+        })
+    });
+    let stop = Stop;
+    let result = f();
+    let frames = RECORDER.with(|recorder| {
+        recorder
+            .borrow_mut()
+            .take()
+            .map(|recorder| recorder.frames)
+            .unwrap_or_default()
+    });
+    drop(stop);
+
+    (result, frames)
+}
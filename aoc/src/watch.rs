@@ -0,0 +1,248 @@
+//! Shows the answers of a day for each of its inputs side by side, and on Linux waits for the
+//! day's files to change so `aoc watch` can show them again.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{self, Answers, Outcome},
+    Answer, Day, Part,
+};
+
+/// The files of a day that are watched, relative to its directory. The `.txt` files in the day's
+/// [`answers::INPUTS_DIR`] are watched as well.
+pub const WATCHED_FILES: [&str; 4] = ["src/solution.rs", "test.txt", "input.txt", "answers.txt"];
+
+/// The result of running a part on one input.
+#[derive(Debug, Clone)]
+pub struct Cell {
+    /// The answer of the part, if it gave one.
+    pub answer: Option<Answer>,
+    /// How the answer compares to the known answer for the input, or why there is none.
+    pub outcome: Outcome,
+}
+
+impl Cell {
+    pub fn is_failure(&self) -> bool {
+        self.outcome.is_failure()
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shown = self
+            .answer
+            .as_ref()
+            .map(Answer::one_line)
+            .unwrap_or_default();
+        match &self.outcome {
+            Outcome::Correct => write!(f, "{shown} ok"),
+            Outcome::Wrong { expected, .. } => write!(f, "{shown} WRONG, expected {expected}"),
+            Outcome::Unknown => write!(f, "{shown}"),
+            Outcome::Todo => write!(f, "todo"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::Invalid(_) => write!(f, "invalid input"),
+            Outcome::NoInput(_) => write!(f, "no input"),
+        }
+    }
+}
+
+/// Both parts of a day run on each of its inputs.
+#[derive(Debug, Clone)]
+pub struct Table {
+    /// The inputs, in the order of the columns.
+    pub files: Vec<PathBuf>,
+    /// A row per part, with a cell per input.
+    pub rows: [Vec<Cell>; 2],
+}
+
+impl Table {
+    /// Runs both parts of a day on the inputs in `dir` that [`answers::check_day`] would check,
+    /// whether or not there is a known answer for them.
+    pub fn run(day: &Day, dir: &Path) -> io::Result<Self> {
+        let answers = Answers::load(&dir.join("answers.txt"))?;
+
+        let mut files = Vec::new();
+        let mut rows = [Vec::new(), Vec::new()];
+        for file in answers::input_files(dir, &answers)? {
+            let path = dir.join(&file);
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(err) => {
+                    for row in &mut rows {
+                        let outcome = Outcome::NoInput(err.to_string());
+                        row.push(Cell {
+                            answer: None,
+                            outcome,
+                        });
+                    }
+                    files.push(path);
+                    continue;
+                }
+            };
+
+            for (part, row) in [Part::One, Part::Two].into_iter().zip(&mut rows) {
+                let result = day.try_solve(part, &input);
+                let answer = result.as_ref().ok().cloned();
+                let outcome = match answers::outcome(result, answers.get(&file, part)) {
+                    Outcome::Invalid(err) => Outcome::Invalid(err.in_file(&path)),
+                    outcome => outcome,
+                };
+                row.push(Cell { answer, outcome });
+            }
+            files.push(path);
+        }
+
+        Ok(Table { files, rows })
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.rows.iter().flatten()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.cells().any(Cell::is_failure)
+    }
+}
+
+/// The parts as rows and the inputs as columns, followed by the errors of inputs that could not
+/// be parsed.
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut columns: Vec<Vec<String>> = self
+            .files
+            .iter()
+            .map(|path| {
+                let name = path.file_name().unwrap_or(path.as_os_str());
+                vec![name.to_string_lossy().into_owned()]
+            })
+            .collect();
+        for row in &self.rows {
+            for (column, cell) in columns.iter_mut().zip(row) {
+                column.push(cell.to_string());
+            }
+        }
+        let widths: Vec<usize> = columns
+            .iter()
+            .map(|column| column.iter().map(|c| c.chars().count()).max().unwrap_or(0))
+            .collect();
+
+        let labels = ["", "part 1", "part 2"];
+        for (i, label) in labels.into_iter().enumerate() {
+            let mut line = format!("{label:<8}");
+            for (column, width) in columns.iter().zip(&widths) {
+                line.push_str(&format!("  {:<width$}", column[i]));
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        // Both parts parse the same input, so its error only has to be shown once
+        for (i, path) in self.files.iter().enumerate() {
+            if let Some(Outcome::Invalid(err)) = self.rows[0].get(i).map(|cell| &cell.outcome) {
+                writeln!(f, "\n{}: {err}", path.display())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use watcher::Watcher;
+
+#[cfg(target_os = "linux")]
+mod watcher {
+    use std::{
+        io,
+        path::{Path, PathBuf},
+        thread,
+        time::Duration,
+    };
+
+    use inotify::{Events, Inotify, WatchDescriptor, WatchMask};
+
+    use super::WATCHED_FILES;
+    use crate::answers::INPUTS_DIR;
+
+    /// How long to wait for more changes after the first, as editors often save a file in
+    /// several steps.
+    const SETTLE: Duration = Duration::from_millis(100);
+
+    /// Waits for the [`WATCHED_FILES`] of a day or its other inputs to change.
+    pub struct Watcher {
+        inotify: Inotify,
+        /// The watched directories, relative to the day's directory.
+        dirs: Vec<(WatchDescriptor, PathBuf)>,
+    }
+
+    impl Watcher {
+        /// Starts watching the files of the day in `dir`, and its inputs directory if it has
+        /// one. Changes made from now on are reported by [`Watcher::wait`].
+        pub fn new(dir: &Path) -> io::Result<Self> {
+            let inotify = Inotify::init()?;
+
+            // Editors tend to save by replacing the file, which a watch on the file itself would
+            // not survive, so the directories they are in are watched instead
+            let parents = WATCHED_FILES.iter().map(|file| {
+                Path::new(file)
+                    .parent()
+                    .unwrap_or(Path::new(""))
+                    .to_path_buf()
+            });
+            let inputs = Some(PathBuf::from(INPUTS_DIR)).filter(|inputs| dir.join(inputs).is_dir());
+
+            let mut dirs = Vec::new();
+            for parent in parents.chain(inputs) {
+                if dirs.iter().any(|(_, d)| *d == parent) {
+                    continue;
+                }
+                let descriptor = inotify.watches().add(
+                    dir.join(&parent),
+                    WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::DELETE,
+                )?;
+                dirs.push((descriptor, parent));
+            }
+
+            Ok(Watcher { inotify, dirs })
+        }
+
+        /// Blocks until at least one of the files has changed, and returns the changed files
+        /// relative to the day's directory.
+        pub fn wait(&mut self) -> io::Result<Vec<PathBuf>> {
+            let mut buffer = [0; 4096];
+            let mut changed = Vec::new();
+            while changed.is_empty() {
+                let events = self.inotify.read_events_blocking(&mut buffer)?;
+                changed.extend(self.watched(events));
+            }
+
+            thread::sleep(SETTLE);
+            loop {
+                match self.inotify.read_events(&mut buffer) {
+                    Ok(events) => changed.extend(self.watched(events)),
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            changed.sort();
+            changed.dedup();
+            Ok(changed)
+        }
+
+        /// The watched files the events are about.
+        fn watched<'a>(&'a self, events: Events<'a>) -> impl Iterator<Item = PathBuf> + 'a {
+            events.filter_map(|event| {
+                let (_, dir) = self.dirs.iter().find(|(d, _)| *d == event.wd)?;
+                let file = dir.join(event.name?);
+                let input = file.parent() == Some(Path::new(INPUTS_DIR))
+                    && file.extension().is_some_and(|ext| ext == "txt");
+                let watched = WATCHED_FILES.iter().any(|w| Path::new(w) == file);
+                (watched || input).then_some(file)
+            })
+        }
+    }
+}
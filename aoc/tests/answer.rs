@@ -0,0 +1,67 @@
+use std::{fs, path::Path};
+
+use aoc::{days, Answer, Part, PixelGrid};
+
+#[test]
+fn integers_match_their_written_form() {
+    let answer = Answer::from(24948u32);
+
+    assert!(answer.matches("24948"));
+    assert!(answer.matches(" 24948\n"));
+    assert!(!answer.matches("24949"));
+    assert!(!answer.matches("24948.0"));
+    assert_eq!(answer.submission(), Ok("24948".to_string()));
+}
+
+#[test]
+fn text_answers() {
+    let answer = Answer::from("VPCDMSLWJ".to_string());
+
+    assert!(answer.matches("VPCDMSLWJ"));
+    assert!(!answer.matches("vpcdmslwj"));
+    assert_eq!(answer.to_string(), "VPCDMSLWJ");
+}
+
+#[test]
+fn pixel_grids_are_drawn() {
+    let mut grid = PixelGrid::new(3, 2);
+    grid.set(0, 0, true);
+    grid.set(2, 1, true);
+    let answer = Answer::from(grid);
+
+    assert_eq!(answer.to_string(), "#..\n..#");
+    assert!(answer.is_multiline());
+    assert!(answer.submission().is_err());
+    assert!(!answer.matches("#..\n..#"));
+}
+
+#[test]
+fn answers_round_trip_through_json() {
+    let mut grid = PixelGrid::new(2, 2);
+    grid.set(1, 0, true);
+
+    for answer in [Answer::from(-3i64), Answer::from("ABC"), Answer::from(grid)] {
+        let json = serde_json::to_string(&answer).unwrap();
+        assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+    }
+    assert_eq!(
+        serde_json::to_string(&Answer::from(12u8)).unwrap(),
+        r#"{"integer":12}"#
+    );
+}
+
+#[test]
+fn crt_is_a_pixel_grid() {
+    let day = days::find(2022, 10).unwrap();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../2022/day10/test.txt");
+    let input = fs::read_to_string(path).unwrap();
+
+    let Answer::Pixels(screen) = day.solve(Part::Two, &input).unwrap() else {
+        panic!("expected a pixel grid");
+    };
+    assert_eq!((screen.width(), screen.height()), (40, 6));
+    assert_eq!(
+        screen.to_string().lines().next(),
+        Some("##..##..##..##..##..##..##..##..##..##..")
+    );
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+};
+
+use aoc::{
+    answers::{self, Answers, Outcome},
+    days,
+};
+
+/// Runs every registered day against the known answers in its `answers.txt`.
+#[test]
+fn known_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let results = thread::scope(|s| {
+        let handles = days::DAYS
+            .iter()
+            .map(|day| {
+                let dir = root.join(day.dir());
+                s.spawn(move || (day, answers::check_inputs(day, &dir)))
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let mut failures = 0;
+    for (day, checks) in results {
+        let checks = checks.unwrap_or_else(|err| panic!("{} day {}: {err}", day.year, day.day));
+        for check in checks {
+            print!("{} day {:>2} {check}", day.year, day.day);
+            failures += check.outcomes.iter().filter(|o| o.is_failure()).count();
+        }
+    }
+
+    assert_eq!(failures, 0, "{failures} answers did not match");
+}
+
+/// A copy of 2022 day 1 with its example, and the inputs of two teammates.
+fn day_with_named_inputs(name: &str, answers: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    let example = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../2022/day01/test.txt");
+    fs::copy(&example, dir.join("test.txt")).unwrap();
+    fs::create_dir(dir.join("inputs")).unwrap();
+    fs::copy(&example, dir.join("inputs/bob.txt")).unwrap();
+    fs::write(dir.join("inputs/alice.txt"), "1\n2\n\n3\n\n4\n").unwrap();
+    fs::write(dir.join("inputs/README.md"), "Not an input\n").unwrap();
+    fs::write(dir.join("answers.txt"), answers).unwrap();
+    dir
+}
+
+#[test]
+fn named_inputs_are_found() {
+    let dir = day_with_named_inputs("named-inputs-found", "extra.txt 1 5\n");
+    let answers = Answers::load(&dir.join("answers.txt")).unwrap();
+
+    assert_eq!(
+        answers::input_files(&dir, &answers).unwrap(),
+        [
+            "test.txt",
+            "inputs/alice.txt",
+            "inputs/bob.txt",
+            "extra.txt"
+        ]
+    );
+    assert_eq!(
+        answers::input_files(&common::temp_dir("named-inputs-none"), &Answers::default()).unwrap(),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn named_inputs_have_their_own_answers() {
+    let dir = day_with_named_inputs(
+        "named-inputs-answers",
+        "inputs/alice.txt 1 4\ninputs/alice.txt 2 10\ninputs/bob.txt 1 24000\ninputs/bob.txt 2 1\n",
+    );
+    let day = days::find(2022, 1).unwrap();
+
+    let checks = answers::check_inputs(day, &dir).unwrap();
+    let files: Vec<&str> = checks.iter().map(|c| c.file.as_str()).collect();
+    assert_eq!(files, ["test.txt", "inputs/alice.txt", "inputs/bob.txt"]);
+
+    let [test, alice, bob] = &checks[..] else {
+        unreachable!()
+    };
+    assert!(!test.passes() && !test.is_failure());
+    assert!(alice.passes());
+    assert_eq!(alice.outcomes, [Outcome::Correct, Outcome::Correct]);
+    assert!(bob.is_failure());
+    assert_eq!(bob.outcomes[0], Outcome::Correct);
+    assert_eq!(
+        bob.to_string(),
+        "inputs/bob.txt: FAILED\n  part 1: ok\n  part 2: FAILED, expected 1 but got 45000\n"
+    );
+}
+
+#[test]
+fn missing_inputs_fail_on_their_own() {
+    let dir = day_with_named_inputs(
+        "named-inputs-missing",
+        "inputs/carol.txt 1 4\ninputs/bob.txt 1 24000\n",
+    );
+    let day = days::find(2022, 1).unwrap();
+
+    let checks = answers::check_inputs(day, &dir).unwrap();
+    let files: Vec<&str> = checks.iter().map(|c| c.file.as_str()).collect();
+    assert_eq!(
+        files,
+        [
+            "test.txt",
+            "inputs/alice.txt",
+            "inputs/bob.txt",
+            "inputs/carol.txt"
+        ]
+    );
+
+    let [.., bob, carol] = &checks[..] else {
+        unreachable!()
+    };
+    assert!(bob.passes());
+    assert!(carol.is_failure());
+    assert!(matches!(carol.outcomes[0], Outcome::NoInput(_)));
+    assert!(carol
+        .to_string()
+        .starts_with("inputs/carol.txt: FAILED\n  part 1: FAILED, no input: "));
+}
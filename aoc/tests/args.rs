@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+use aoc::{
+    args::{apply_env, misspelled_vars, DayCli, EnvError, RunArgs},
+    Part,
+};
+use clap::Parser;
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+fn parse_args(args: &[&str]) -> RunArgs {
+    DayCli::try_parse_from(["day04"].iter().chain(args))
+        .unwrap()
+        .run
+}
+
+#[test]
+fn runs_both_parts_by_default() {
+    let args = parse_args(&[]);
+
+    assert_eq!(args.parts(), [Part::One, Part::Two]);
+    assert!(args.inputs.is_empty());
+    assert!(!args.quiet);
+}
+
+#[test]
+fn takes_several_inputs() {
+    let args = parse_args(&[
+        "-p",
+        "2",
+        "-q",
+        "--input",
+        "test.txt",
+        "-",
+        "-i",
+        "other.txt",
+    ]);
+
+    assert_eq!(args.parts(), [Part::Two]);
+    assert_eq!(
+        args.inputs,
+        ["test.txt", "-", "other.txt"].map(PathBuf::from)
+    );
+    assert!(args.quiet);
+}
+
+#[test]
+fn rejects_invalid_parts() {
+    assert!(DayCli::try_parse_from(["day04", "--part", "3"]).is_err());
+}
+
+#[test]
+fn env_vars_from_the_makefiles() {
+    let mut args = RunArgs::default();
+    apply_env(
+        &mut args,
+        vars(&[("part", "part2"), ("test_file", "./test.txt")]),
+    )
+    .unwrap();
+
+    assert_eq!(args.parts(), [Part::Two]);
+    assert_eq!(args.inputs, [PathBuf::from("./test.txt")]);
+}
+
+#[test]
+fn arguments_take_precedence_over_env_vars() {
+    let mut args = parse_args(&["-p", "1", "-i", "input.txt"]);
+    apply_env(
+        &mut args,
+        vars(&[("part", "part2"), ("test_file", "./test.txt")]),
+    )
+    .unwrap();
+
+    assert_eq!(args.parts(), [Part::One]);
+    assert_eq!(args.inputs, [PathBuf::from("input.txt")]);
+}
+
+#[test]
+fn invalid_part_env_var() {
+    let mut args = RunArgs::default();
+    let err = apply_env(&mut args, vars(&[("part", "part3")])).unwrap_err();
+
+    assert_eq!(err, EnvError::InvalidPart("part3".to_string()));
+}
+
+#[test]
+fn finds_misspelled_env_vars() {
+    let names = [
+        "PART",
+        "test-file",
+        "TestFile",
+        "part",
+        "test_file",
+        "PATH",
+        "HOME",
+    ];
+    let misspelled = misspelled_vars(names.map(String::from));
+
+    assert_eq!(
+        misspelled,
+        [
+            ("PART".to_string(), "part"),
+            ("test-file".to_string(), "test_file"),
+            ("TestFile".to_string(), "test_file"),
+        ]
+    );
+}
@@ -0,0 +1,61 @@
+use std::{fs, path::Path, time::Duration};
+
+use aoc::{
+    bench::{Measurement, Options, Timings},
+    days,
+};
+
+fn bench_example(year: u16, day: u8, options: &Options) -> Timings {
+    let solution = days::find(year, day).unwrap();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(solution.dir())
+        .join("test.txt");
+    let input = fs::read_to_string(path).unwrap();
+
+    solution.bench(&input, options).unwrap()
+}
+
+#[test]
+fn takes_the_requested_samples() {
+    let options = Options {
+        warmup: 2,
+        samples: 4,
+        max_time: Duration::from_secs(10),
+        ..Options::default()
+    };
+    let timings = bench_example(2022, 1, &options);
+
+    for measurement in [timings.parse, timings.part_one, timings.part_two] {
+        let Measurement::Sampled(stats) = measurement else {
+            panic!("expected samples, got {measurement:?}");
+        };
+        assert_eq!(stats.samples, 4);
+        assert!(stats.min_ns <= stats.median_ns);
+    }
+}
+
+#[test]
+fn unfinished_parts_are_reported_as_panicked() {
+    let options = Options {
+        samples: 1,
+        ..Options::default()
+    };
+    let timings = bench_example(2022, 16, &options);
+
+    assert!(matches!(timings.parse, Measurement::Sampled(_)));
+    assert_eq!(timings.part_one, Measurement::Panicked);
+}
+
+#[test]
+fn timings_round_trip_through_json() {
+    let options = Options {
+        samples: 1,
+        ..Options::default()
+    };
+    let timings = vec![bench_example(2022, 6, &options)];
+
+    let json = serde_json::to_string(&timings).unwrap();
+    let parsed: Vec<Timings> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, timings);
+}
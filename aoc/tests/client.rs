@@ -0,0 +1,176 @@
+#![cfg(feature = "download_input")]
+
+mod common;
+
+use std::{
+    fs,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use aoc::{
+    cache::Cache,
+    client::{Client, Error},
+};
+use common::{StandIn, SESSION};
+
+const INPUT: &str = "1000\n2000\n\n3000\n";
+
+fn input_server() -> StandIn {
+    StandIn::start(|request| match request.path.as_str() {
+        "/2022/day/1/input" => (200, INPUT.to_string()),
+        _ => (404, "404 Not Found".to_string()),
+    })
+}
+
+#[test]
+fn downloads_input_once() {
+    let server = input_server();
+    let cache = Cache::new(common::temp_dir("downloads_input_once"));
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    assert_eq!(client.cached_input(&cache, 2022, 1).unwrap(), INPUT);
+    assert_eq!(client.cached_input(&cache, 2022, 1).unwrap(), INPUT);
+
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(
+        fs::read_to_string(cache.root().join("2022").join("01").join("input.txt")).unwrap(),
+        INPUT
+    );
+}
+
+#[test]
+fn uses_base_url_path() {
+    let server = StandIn::start(|request| match request.path.as_str() {
+        "/mirror/2022/day/1/input" => (200, INPUT.to_string()),
+        _ => (404, "404 Not Found".to_string()),
+    });
+    let client = Client::new(&format!("{}/mirror", server.url), SESSION).unwrap();
+
+    assert_eq!(client.input(2022, 1).unwrap(), INPUT);
+}
+
+#[test]
+fn missing_session() {
+    assert!(matches!(
+        Client::new("http://127.0.0.1:1", " "),
+        Err(Error::MissingSession)
+    ));
+}
+
+#[test]
+fn expired_session() {
+    let server = input_server();
+    let client = Client::new(&server.url, "expired").unwrap();
+
+    assert!(matches!(client.input(2022, 1), Err(Error::ExpiredSession)));
+}
+
+#[test]
+fn expired_session_is_not_cached() {
+    let server = input_server();
+    let cache = Cache::new(common::temp_dir("expired_session_is_not_cached"));
+    let client = Client::new(&server.url, "expired").unwrap();
+
+    assert!(client.cached_input(&cache, 2022, 1).is_err());
+    assert!(!cache.input_path(2022, 1).exists());
+}
+
+#[test]
+fn unavailable_day() {
+    let server = input_server();
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    assert!(matches!(
+        client.input(2022, 25),
+        Err(Error::NotAvailable {
+            year: 2022,
+            day: 25
+        })
+    ));
+}
+
+#[test]
+fn puzzle_is_downloaded_again_until_both_parts_are_there() {
+    let pages = [
+        include_str!("pages/2022-01-part1.html"),
+        include_str!("pages/2022-01.html"),
+    ];
+    let served = AtomicUsize::new(0);
+    let server = StandIn::start(move |request| match request.path.as_str() {
+        "/2022/day/1" => {
+            let i = served.fetch_add(1, Ordering::SeqCst).min(1);
+            (200, pages[i].to_string())
+        }
+        _ => (404, "404 Not Found".to_string()),
+    });
+    let cache = Cache::new(common::temp_dir("puzzle_is_downloaded_again"));
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    assert_eq!(client.cached_puzzle(&cache, 2022, 1).unwrap(), pages[0]);
+    assert_eq!(client.cached_puzzle(&cache, 2022, 1).unwrap(), pages[1]);
+    assert_eq!(client.cached_puzzle(&cache, 2022, 1).unwrap(), pages[1]);
+
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(
+        fs::read_to_string(cache.puzzle_path(2022, 1)).unwrap(),
+        pages[1]
+    );
+}
+
+const LEADERBOARD: &str = include_str!("leaderboards/private.json");
+
+fn leaderboard_server() -> StandIn {
+    StandIn::start(|request| match request.path.as_str() {
+        "/2022/leaderboard/private/view/101.json" => (200, LEADERBOARD.to_string()),
+        // Non-members are sent to the list of their own leaderboards
+        "/2022/leaderboard/private/view/999.json" => {
+            (200, "<!DOCTYPE html><html></html>".to_string())
+        }
+        _ => (404, "404 Not Found".to_string()),
+    })
+}
+
+#[test]
+fn leaderboard_is_only_downloaded_again_once_stale() {
+    let server = leaderboard_server();
+    let cache = Cache::new(common::temp_dir("leaderboard_is_only_downloaded_again"));
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    assert_eq!(
+        client.cached_leaderboard(&cache, 2022, 101).unwrap(),
+        LEADERBOARD
+    );
+    assert_eq!(
+        client.cached_leaderboard(&cache, 2022, 101).unwrap(),
+        LEADERBOARD
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    let path = cache.leaderboard_path(2022, 101);
+    assert_eq!(path, cache.root().join("2022").join("leaderboard-101.json"));
+    let an_hour_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(an_hour_ago)
+        .unwrap();
+
+    client.cached_leaderboard(&cache, 2022, 101).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn leaderboard_of_others() {
+    let server = leaderboard_server();
+    let cache = Cache::new(common::temp_dir("leaderboard_of_others"));
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    for id in [999, 5] {
+        assert!(matches!(
+            client.cached_leaderboard(&cache, 2022, id),
+            Err(Error::NoLeaderboard(i)) if i == id
+        ));
+        assert!(!cache.leaderboard_path(2022, id).exists());
+    }
+}
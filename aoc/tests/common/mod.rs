@@ -0,0 +1,94 @@
+//! A local stand-in for the advent of code site, so the client can be tested without network
+//! access.
+
+#![allow(dead_code)]
+
+use std::{
+    env, fs,
+    path::PathBuf,
+    process,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use tiny_http::{Response, Server};
+
+pub const SESSION: &str = "0123456789abcdef";
+
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+pub struct StandIn {
+    pub url: String,
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl StandIn {
+    /// Starts a server that rejects requests without the [`SESSION`] cookie the same way the
+    /// real site does, and answers all other requests with `handler`.
+    pub fn start(handler: impl Fn(&Recorded) -> (u16, String) + Send + 'static) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let thread_server = server.clone();
+        let thread_requests = requests.clone();
+        thread::spawn(move || {
+            for mut request in thread_server.incoming_requests() {
+                let authenticated = request.headers().iter().any(|h| {
+                    h.field.equiv("Cookie")
+                        && h.value.as_str().contains(&format!("session={SESSION}"))
+                });
+
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let recorded = Recorded {
+                    method: request.method().to_string(),
+                    path: request.url().to_string(),
+                    body,
+                };
+                thread_requests.lock().unwrap().push(recorded.clone());
+
+                let (status, body) = if authenticated {
+                    handler(&recorded)
+                } else {
+                    (
+                        400,
+                        "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+                            .to_string(),
+                    )
+                };
+                let _ = request.respond(Response::from_string(body).with_status_code(status));
+            }
+        });
+
+        StandIn {
+            url,
+            server,
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for StandIn {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
+
+/// An empty directory unique to this test process and `name`.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-test-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,172 @@
+use aoc::{
+    days,
+    generate::{self, GENERATORS},
+    solution::Error,
+    Part,
+};
+
+#[test]
+fn every_day_has_a_generator() {
+    for day in days::DAYS {
+        assert!(
+            generate::find(day.year, day.day).is_some(),
+            "{} day {}",
+            day.year,
+            day.day
+        );
+    }
+    assert!(generate::find(2022, 25).is_none());
+}
+
+#[test]
+fn seeds_give_the_same_input() {
+    for generator in GENERATORS {
+        let input = generator.generate(7, 20);
+        assert_eq!(input, generator.generate(7, 20));
+        assert_ne!(input, generator.generate(8, 20));
+    }
+}
+
+#[test]
+fn generated_inputs_parse() {
+    for generator in GENERATORS {
+        let day = days::find(generator.year, generator.day).unwrap();
+        for size in [0, 1, 2, 5, 30, generator.size] {
+            for seed in 0..5 {
+                let input = generator.generate(seed, size);
+                if let Err(err) = day.parse(&input) {
+                    panic!(
+                        "{} day {} with seed {seed} and size {size}: {err}\n{input}",
+                        day.year, day.day
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    // Unfinished parts panic on `todo!()`, which is fine here
+    std::panic::set_hook(Box::new(|_| {}));
+
+    for generator in GENERATORS {
+        // Part 1 looks at 18 million positions for every sensor
+        if (generator.year, generator.day) == (2022, 15) {
+            continue;
+        }
+        let day = days::find(generator.year, generator.day).unwrap();
+        for seed in 0..3 {
+            let input = generator.generate(seed, generator.size);
+            for part in [Part::One, Part::Two] {
+                match day.try_solve(part, &input) {
+                    Ok(_) => {}
+                    Err(Error::Panic(panic)) if panic.is_todo() => {}
+                    Err(err) => panic!(
+                        "{} day {} {part} with seed {seed}: {err}",
+                        day.year, day.day
+                    ),
+                }
+            }
+        }
+    }
+
+    let _ = std::panic::take_hook();
+}
+
+fn sensors(input: &str) -> Vec<((i64, i64), i64)> {
+    let numbers = |s: &str| -> Vec<i64> {
+        s.split(|c: char| !c.is_ascii_digit() && c != '-')
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse().unwrap())
+            .collect()
+    };
+    input
+        .lines()
+        .map(|l| {
+            let n = numbers(l);
+            ((n[0], n[1]), (n[0] - n[2]).abs() + (n[1] - n[3]).abs())
+        })
+        .collect()
+}
+
+fn covered(sensors: &[((i64, i64), i64)], (x, y): (i64, i64)) -> bool {
+    sensors
+        .iter()
+        .any(|&((sx, sy), range)| (sx - x).abs() + (sy - y).abs() <= range)
+}
+
+#[test]
+fn distress_beacon_is_the_only_gap() {
+    const EDGE: i64 = 4_000_000;
+    let generator = generate::find(2022, 15).unwrap();
+
+    for seed in 0..5 {
+        let sensors = sensors(&generator.generate(seed, generator.size));
+
+        // A gap of one position lies where the edges just outside of two sensors cross
+        let mut sums = Vec::new();
+        let mut differences = Vec::new();
+        for &((x, y), range) in &sensors {
+            for side in [-1, 1] {
+                sums.push(x + y + side * (range + 1));
+                differences.push(x - y + side * (range + 1));
+            }
+        }
+        let mut gaps = Vec::new();
+        for &sum in &sums {
+            for &difference in &differences {
+                let (x, y) = ((sum + difference) / 2, (sum - difference) / 2);
+                if (sum + difference) % 2 == 0
+                    && (0..=EDGE).contains(&x)
+                    && (0..=EDGE).contains(&y)
+                    && !covered(&sensors, (x, y))
+                {
+                    gaps.push((x, y));
+                }
+            }
+        }
+        gaps.sort();
+        gaps.dedup();
+        assert_eq!(gaps.len(), 1, "seed {seed}: {gaps:?}");
+        let distress = gaps[0];
+
+        // Any other gap would show up on rows through it as well
+        for y in (0..=EDGE).step_by(99_991).chain([distress.1]) {
+            let mut ranges: Vec<(i64, i64)> = sensors
+                .iter()
+                .filter_map(|&((sx, sy), range)| {
+                    let reach = range - (sy - y).abs();
+                    (reach >= 0).then_some((sx - reach, sx + reach))
+                })
+                .collect();
+            ranges.sort();
+            let mut next = 0;
+            for (from, to) in ranges {
+                if from > next && next <= EDGE {
+                    assert_eq!((next, y), distress, "seed {seed}");
+                    assert_eq!(from, next + 1, "seed {seed}");
+                }
+                next = next.max(to + 1);
+            }
+            assert!(next > EDGE, "seed {seed}: row {y} ends at {next}");
+        }
+    }
+}
+
+#[test]
+fn humn_is_listened_to_once() {
+    let generator = generate::find(2022, 21).unwrap();
+    for seed in 0..5 {
+        let input = generator.generate(seed, generator.size);
+        let listening: Vec<&str> = input
+            .lines()
+            .filter(|l| l.split(": ").nth(1).unwrap().contains("humn"))
+            .collect();
+        assert_eq!(listening.len(), 1, "seed {seed}");
+        assert!(input
+            .lines()
+            .any(|l| l.starts_with("root: ") && l.contains(" + ")));
+        assert!(input.lines().any(|l| l.starts_with("humn: ")));
+    }
+}
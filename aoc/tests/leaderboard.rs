@@ -0,0 +1,137 @@
+use aoc::leaderboard::{duration, unlock_time, Leaderboard, StarTimes};
+
+const JSON: &str = include_str!("leaderboards/private.json");
+
+#[test]
+fn unlock_times() {
+    assert_eq!(unlock_time(2022, 1), 1_669_870_800);
+    assert_eq!(unlock_time(2022, 25), 1_669_870_800 + 24 * 86400);
+    // 2020-12-01T05:00:00Z
+    assert_eq!(unlock_time(2020, 1), 1_606_798_800);
+}
+
+#[test]
+fn durations() {
+    assert_eq!(duration(None), "-");
+    assert_eq!(duration(Some(0)), "0:00:00");
+    assert_eq!(duration(Some(3723)), "1:02:03");
+    assert_eq!(duration(Some(2 * 86400 + 59)), "2d 0:00:59");
+}
+
+#[test]
+fn star_times_and_deltas() {
+    let leaderboard = Leaderboard::parse(JSON).unwrap();
+    assert_eq!(leaderboard.event, 2022);
+    assert_eq!(leaderboard.owner_id, 101);
+
+    let alice = leaderboard.member(101).unwrap();
+    let times = leaderboard.star_times(alice, 2);
+    assert_eq!(
+        times,
+        StarTimes {
+            part_one: Some(1000),
+            part_two: Some(4000)
+        }
+    );
+    assert_eq!(times.delta(), Some(3000));
+
+    let bob = leaderboard.member(202).unwrap();
+    let times = leaderboard.star_times(bob, 3);
+    assert_eq!(times.part_one, Some(50000));
+    assert_eq!(times.delta(), None);
+    assert_eq!(
+        leaderboard.star_times(bob, 4),
+        StarTimes {
+            part_one: None,
+            part_two: None
+        }
+    );
+
+    let anonymous = leaderboard.member(303).unwrap();
+    assert_eq!(anonymous.display_name(), "(anonymous user #303)");
+    assert_eq!(
+        leaderboard.star_times(anonymous, 1).part_one,
+        Some(2 * 86400 + 3723)
+    );
+}
+
+#[test]
+fn points_match_the_local_scores() {
+    let leaderboard = Leaderboard::parse(JSON).unwrap();
+    assert_eq!(leaderboard.days(), [1, 2, 3]);
+
+    let points = leaderboard.points(1, 1);
+    assert_eq!(
+        points.into_iter().collect::<Vec<_>>(),
+        [(101, 3), (202, 4), (303, 2)]
+    );
+
+    let history = leaderboard.history();
+    let last = history.last().unwrap();
+    for member in leaderboard.members() {
+        let (_, score) = last.scores.iter().find(|(id, _)| *id == member.id).unwrap();
+        assert_eq!(*score, member.local_score, "{}", member.display_name());
+    }
+    let names: Vec<String> = leaderboard
+        .members()
+        .iter()
+        .map(|m| m.display_name())
+        .collect();
+    assert_eq!(names, ["Bob", "Alice", "(anonymous user #303)", "Carol"]);
+}
+
+#[test]
+fn ranking_history() {
+    let leaderboard = Leaderboard::parse(JSON).unwrap();
+    let history = leaderboard.history();
+    let days: Vec<u8> = history.iter().map(|s| s.day).collect();
+    assert_eq!(days, [1, 2, 3]);
+
+    // Alice and Bob are tied after day 1, which the name decides
+    assert_eq!(history[0].scores, [(101, 7), (202, 7), (303, 4), (404, 0)]);
+    assert_eq!(history[0].place(202), Some(2));
+    assert_eq!(history[1].place(202), Some(1));
+    assert_eq!(history[1].scores[..2], [(202, 15), (101, 13)]);
+    assert_eq!(history[2].scores[0], (202, 19));
+    assert_eq!(history[2].place(1), None);
+}
+
+#[test]
+fn report() {
+    let leaderboard = Leaderboard::parse(JSON).unwrap();
+    let report = leaderboard.report(None).to_string();
+    let expected = "\
+2022 day 1
+  member                     part 1      part 2       delta  place
+  Alice                     0:05:00     0:10:00     0:05:00      1
+  Bob                       0:03:20     0:15:00     0:11:40      2
+  (anonymous user #303)  2d 1:02:03  2d 2:00:00     0:57:57      3
+
+2022 day 2
+  member                     part 1      part 2       delta  place
+  Bob                       0:13:20     0:20:00     0:06:40      1
+  Alice                     0:16:40     1:06:40     0:50:00      2
+
+2022 day 3
+  member                     part 1      part 2       delta  place
+  Bob                      13:53:20           -           -      1
+
+places after each day
+  member                  1   2   3  score
+  Bob                     2   1   1     19
+  Alice                   1   2   2     13
+  (anonymous user #303)   3   3   3      4
+  Carol                   4   4   4      0
+";
+    assert_eq!(report, expected);
+
+    let day = leaderboard.report(Some(3)).to_string();
+    assert!(day.starts_with("2022 day 3\n"));
+    assert!(!day.contains("places after each day"));
+}
+
+#[test]
+fn invalid_json() {
+    assert!(Leaderboard::parse("<!DOCTYPE html>").is_err());
+    assert!(Leaderboard::parse(r#"{"event":"twenty","owner_id":1,"members":{}}"#).is_err());
+}
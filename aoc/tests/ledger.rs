@@ -0,0 +1,91 @@
+mod common;
+
+use aoc::{
+    ledger::{Ledger, Rejection, Verdict},
+    Part,
+};
+
+fn ledger(name: &str) -> Ledger {
+    Ledger::load(common::temp_dir(name).join("guesses.txt")).unwrap()
+}
+
+#[test]
+fn rejects_repeated_guess() {
+    let mut ledger = ledger("rejects_repeated_guess");
+    ledger.record(Part::One, "ABC", Verdict::Wrong).unwrap();
+
+    assert_eq!(
+        ledger.check(Part::One, "ABC"),
+        Err(Rejection::Repeated {
+            verdict: Verdict::Wrong
+        })
+    );
+    assert_eq!(ledger.check(Part::One, "ABD"), Ok(()));
+    assert_eq!(ledger.check(Part::Two, "ABC"), Ok(()));
+}
+
+#[test]
+fn rejects_guesses_outside_known_bounds() {
+    let mut ledger = ledger("rejects_guesses_outside_known_bounds");
+    ledger.record(Part::Two, "500", Verdict::TooHigh).unwrap();
+    ledger.record(Part::Two, "100", Verdict::TooLow).unwrap();
+
+    assert_eq!(
+        ledger.check(Part::Two, "600"),
+        Err(Rejection::AtLeast {
+            too_high: "500".to_string()
+        })
+    );
+    assert_eq!(
+        ledger.check(Part::Two, "-3"),
+        Err(Rejection::AtMost {
+            too_low: "100".to_string()
+        })
+    );
+    assert_eq!(ledger.check(Part::Two, "101"), Ok(()));
+    assert_eq!(ledger.check(Part::Two, "499"), Ok(()));
+    assert_eq!(ledger.check(Part::One, "600"), Ok(()));
+}
+
+#[test]
+fn rejects_solved_part() {
+    let mut ledger = ledger("rejects_solved_part");
+    ledger.record(Part::One, "42", Verdict::Correct).unwrap();
+
+    assert_eq!(
+        ledger.check(Part::One, "43"),
+        Err(Rejection::AlreadySolved {
+            answer: "42".to_string()
+        })
+    );
+}
+
+#[test]
+fn allows_retrying_rate_limited_guess() {
+    let mut ledger = ledger("allows_retrying_rate_limited_guess");
+    ledger
+        .record(
+            Part::One,
+            "42",
+            Verdict::RateLimited {
+                wait: Some("30s".to_string()),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(ledger.check(Part::One, "42"), Ok(()));
+}
+
+#[test]
+fn guesses_are_persisted() {
+    let path = common::temp_dir("guesses_are_persisted").join("guesses.txt");
+    let mut ledger = Ledger::load(&path).unwrap();
+    ledger.record(Part::One, "1000", Verdict::TooLow).unwrap();
+    ledger.record(Part::Two, "HELLO", Verdict::Correct).unwrap();
+
+    let ledger = Ledger::load(&path).unwrap();
+    assert_eq!(ledger.guesses().len(), 2);
+    assert_eq!(ledger.guesses()[0].verdict, Verdict::TooLow);
+    assert_eq!(ledger.guesses()[1].answer, "HELLO");
+    assert!(ledger.check(Part::One, "999").is_err());
+}
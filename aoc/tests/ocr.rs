@@ -0,0 +1,88 @@
+use std::ops::Range;
+
+use aoc::{
+    ocr::{self, Error, Unrecognized},
+    PixelGrid,
+};
+
+fn screen(rows: &[&str]) -> PixelGrid {
+    let mut screen = PixelGrid::new(rows[0].len(), rows.len());
+    for (y, row) in rows.iter().enumerate() {
+        for (x, pixel) in row.bytes().enumerate() {
+            screen.set(x, y, pixel == b'#');
+        }
+    }
+    screen
+}
+
+fn unrecognized(index: usize, columns: Range<usize>) -> Unrecognized {
+    Unrecognized { index, columns }
+}
+
+#[test]
+fn reads_letters() {
+    let screen = screen(&[
+        "###..####.###...##..####.####...##.###..",
+        "#..#....#.#..#.#..#....#.#.......#.#..#.",
+        "#..#...#..###..#......#..###.....#.###..",
+        "###...#...#..#.#.##..#...#.......#.#..#.",
+        "#....#....#..#.#..#.#....#....#..#.#..#.",
+        "#....####.###...###.####.####..##..###..",
+    ]);
+
+    assert_eq!(ocr::read(&screen), Ok("PZBGZEJB".to_string()));
+}
+
+#[test]
+fn the_last_letter_does_not_need_a_gap() {
+    let screen = screen(&[
+        "#..#.####",
+        "#..#.#...",
+        "####.###.",
+        "#..#.#...",
+        "#..#.#...",
+        "#..#.####",
+    ]);
+
+    assert_eq!(ocr::read(&screen), Ok("HE".to_string()));
+}
+
+#[test]
+fn reports_unrecognized_glyphs_with_their_position() {
+    let screen = screen(&[
+        "###..#..#.#...#",
+        "#..#.#..#.#...#",
+        "#..#.####.#.#.#",
+        "#..#.#..#.#.#.#",
+        "#..#.#..#.#.#.#",
+        "###..#..#..#.#.",
+    ]);
+
+    let err = ocr::read(&screen).unwrap_err();
+    assert_eq!(
+        err,
+        Error::Unrecognized(vec![unrecognized(0, 0..5), unrecognized(2, 10..15)])
+    );
+    assert_eq!(
+        err.to_string(),
+        "unrecognized glyph 1 (columns 0-4), glyph 3 (columns 10-14)"
+    );
+}
+
+#[test]
+fn blank_glyphs_are_not_letters() {
+    let screen = PixelGrid::new(10, 6);
+
+    assert_eq!(
+        ocr::read(&screen),
+        Err(Error::Unrecognized(vec![
+            unrecognized(0, 0..5),
+            unrecognized(1, 5..10)
+        ]))
+    );
+}
+
+#[test]
+fn screens_of_the_wrong_height() {
+    assert_eq!(ocr::read(&PixelGrid::new(40, 8)), Err(Error::Height(8)));
+}
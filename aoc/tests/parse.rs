@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use aoc::{
+    answers::{self, Outcome},
+    days,
+    parse::{ParseError, Parser},
+    Part,
+};
+
+fn parse_error(year: u16, day: u8, input: &str) -> ParseError {
+    let solution = days::find(year, day).unwrap();
+    match solution.solve(Part::One, input) {
+        Ok(answer) => panic!("expected a parse error, got {answer}"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn points_at_the_line_and_column() {
+    let input = "first\nsecond line\nthird";
+    let err = ParseError::new(input, &input[13..], "`x`");
+
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 8);
+    assert_eq!(err.source_line, "second line");
+    assert_eq!(err.found(), "`l`");
+}
+
+#[test]
+fn highlights_the_offending_line() {
+    let input = "2-4,6-8\n2-3,4-5\n5-7";
+    let err = parse_error(2022, 4, input).in_file(Path::new("2022/day04/input.txt"));
+
+    assert_eq!(
+        err.to_string(),
+        "expected `,` at 2022/day04/input.txt:3:4, found end of line\n  \
+           |\n\
+         3 | 5-7\n  \
+           |    ^ expected `,`"
+    );
+}
+
+#[test]
+fn missing_delimiters_point_past_the_end() {
+    let input = "move 1 from 2";
+    let p = Parser::new(input);
+    let err = p.split_once(input, " to ").unwrap_err();
+
+    assert_eq!((err.line, err.column), (1, 14));
+    assert_eq!(err.expected, "` to `");
+}
+
+#[test]
+fn invalid_numbers() {
+    let err = parse_error(2022, 1, "1000\n2000\n\n30x0\n");
+
+    assert_eq!((err.line, err.column), (4, 1));
+    assert_eq!(err.expected, "a number");
+}
+
+#[test]
+fn unknown_tokens() {
+    let err = parse_error(2022, 9, "R 4\nU 4\nX 3\n");
+    assert_eq!((err.line, err.column), (3, 1));
+    assert_eq!(err.expected, "one of R, L, U, D");
+
+    let err = parse_error(2022, 13, "[1,1,3]\n[1,[2;3]]\n");
+    assert_eq!((err.line, err.column), (2, 6));
+    assert_eq!(err.expected, "`,` or `]`");
+}
+
+#[test]
+fn truncated_input() {
+    let err = parse_error(2022, 11, "Monkey 0:\n  Starting items: 79, 98");
+
+    // Caught by the shape of the input before the parser gets to it
+    assert_eq!((err.line, err.column), (2, 25));
+    assert_eq!(err.expected, "blocks of 6 lines");
+}
+
+#[test]
+fn checks_report_invalid_input() {
+    let day = days::find(2022, 2).unwrap();
+    let outcome = answers::check(day, Part::One, "A Y\nB Q\n", "15");
+
+    let Outcome::Invalid(err) = &outcome else {
+        panic!("expected invalid input, got {outcome:?}");
+    };
+    assert_eq!((err.line, err.column), (2, 3));
+    assert!(outcome.is_failure());
+}
@@ -0,0 +1,226 @@
+//! Property tests for the hand-written parsers: valid inputs that are generated the way the
+//! puzzles describe them have to parse, and no input at all may make a parser panic.
+//!
+//! The same parsers are fuzzed by the targets in `fuzz`.
+
+use aoc::{
+    days,
+    days::year2022::{Day05, Day07, Day11, Day13},
+    Part, Solution,
+};
+use proptest::{collection::vec, prelude::*};
+
+fn parses(year: u16, day: u8, input: &str) -> Result<(), TestCaseError> {
+    let day = days::find(year, day).unwrap();
+    match day.parse(input) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(TestCaseError::fail(format!("{err}\n{input}"))),
+    }
+}
+
+fn solves(year: u16, day: u8, part: Part, input: &str) -> Result<(), TestCaseError> {
+    let day = days::find(year, day).unwrap();
+    match day.try_solve(part, input) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(TestCaseError::fail(format!("{part}: {err}\n{input}"))),
+    }
+}
+
+/// A packet of 2022 day 13, written the way the input does.
+fn packet() -> impl Strategy<Value = String> {
+    let integer = prop_oneof![0u32..=10, any::<u32>()].prop_map(|n| n.to_string());
+    let value = integer.prop_recursive(4, 32, 5, |inner| {
+        vec(inner, 0..5).prop_map(|values| format!("[{}]", values.join(",")))
+    });
+    vec(value, 0..5).prop_map(|values| format!("[{}]", values.join(",")))
+}
+
+/// The starting stacks of 2022 day 5 and moves between them, drawn the way the input does, with
+/// or without the trailing spaces.
+fn crates() -> impl Strategy<Value = String> {
+    let stacks = vec(vec(prop::char::range('A', 'Z'), 0..6), 1..=9);
+    let moves = vec((any::<usize>(), any::<usize>(), any::<usize>()), 0..20);
+    (stacks, moves, any::<bool>()).prop_map(|(mut stacks, moves, padded)| {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines = Vec::new();
+        for level in (0..height).rev() {
+            let row: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            lines.push(row.join(" "));
+        }
+        let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {n} ")).collect();
+        lines.push(numbers.join(" "));
+        if !padded {
+            lines = lines.iter().map(|l| l.trim_end().to_string()).collect();
+        }
+        lines.push(String::new());
+
+        // Only moves that take crates from a stack that has them
+        for (from, to, count) in moves {
+            let filled: Vec<usize> = (0..stacks.len())
+                .filter(|&i| !stacks[i].is_empty())
+                .collect();
+            if filled.is_empty() {
+                break;
+            }
+            let from = filled[from % filled.len()];
+            let to = to % stacks.len();
+            let count = count % stacks[from].len() + 1;
+            for _ in 0..count {
+                let c = stacks[from].pop().unwrap();
+                stacks[to].push(c);
+            }
+            lines.push(format!("move {count} from {} to {}", from + 1, to + 1));
+        }
+
+        lines.join("\n") + "\n"
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Dir {
+    files: Vec<(String, u32)>,
+    dirs: Vec<(String, Dir)>,
+}
+
+fn name() -> impl Strategy<Value = String> {
+    "[a-z]{1,8}(\\.[a-z]{1,3})?"
+}
+
+fn dir() -> impl Strategy<Value = Dir> {
+    let files = || vec((name(), 1u32..=400_000), 0..5);
+    let leaf = files().prop_map(|files| Dir {
+        files,
+        dirs: Vec::new(),
+    });
+    leaf.prop_recursive(4, 32, 4, move |inner| {
+        (files(), vec((name(), inner), 0..4)).prop_map(|(files, dirs)| Dir { files, dirs })
+    })
+}
+
+/// A terminal transcript of 2022 day 7 that lists every directory of a file system once.
+fn transcript() -> impl Strategy<Value = String> {
+    fn list(dir: &Dir, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_string());
+        // Names only have to be unique within a directory
+        for (i, (name, _)) in dir.dirs.iter().enumerate() {
+            lines.push(format!("dir {name}{i}"));
+        }
+        for (i, (name, size)) in dir.files.iter().enumerate() {
+            lines.push(format!("{size} {name}_{i}"));
+        }
+        for (i, (name, sub)) in dir.dirs.iter().enumerate() {
+            lines.push(format!("$ cd {name}{i}"));
+            list(sub, lines);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+
+    dir().prop_map(|root| {
+        let mut lines = vec!["$ cd /".to_string()];
+        list(&root, &mut lines);
+        lines.join("\n") + "\n"
+    })
+}
+
+/// The notes on the monkeys of 2022 day 11.
+fn monkeys() -> impl Strategy<Value = String> {
+    let operation = prop_oneof![
+        (1u64..20).prop_map(|n| format!("old * {n}")),
+        (1u64..20).prop_map(|n| format!("old + {n}")),
+        Just("old * old".to_string()),
+        Just("old + old".to_string()),
+    ];
+    let divisor = prop::sample::select(vec![2u64, 3, 5, 7, 11, 13, 17, 19, 23]);
+    let monkey = (
+        vec(1u64..100, 1..5),
+        operation,
+        divisor,
+        any::<usize>(),
+        any::<usize>(),
+    );
+    vec(monkey, 2..8).prop_map(|monkeys| {
+        let count = monkeys.len();
+        let notes: Vec<String> = monkeys
+            .into_iter()
+            .enumerate()
+            .map(|(i, (items, operation, divisor, if_true, if_false))| {
+                let items: Vec<String> = items.iter().map(u64::to_string).collect();
+                // Monkeys never throw to themselves
+                let if_true = (i + 1 + if_true % (count - 1)) % count;
+                let if_false = (i + 1 + if_false % (count - 1)) % count;
+                format!(
+                    "Monkey {i}:\n  Starting items: {}\n  Operation: new = {operation}\n  Test: divisible by {divisor}\n    If true: throw to monkey {if_true}\n    If false: throw to monkey {if_false}",
+                    items.join(", ")
+                )
+            })
+            .collect();
+        notes.join("\n\n") + "\n"
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn packets_round_trip(pairs in vec((packet(), packet()), 1..8)) {
+        let input: Vec<String> = pairs.iter().map(|(a, b)| format!("{a}\n{b}")).collect();
+        let input = input.join("\n\n") + "\n";
+
+        let parsed: Vec<_> = Day13::parse(&input).unwrap().collect();
+        prop_assert_eq!(parsed.len(), pairs.len());
+        for (i, (pair, (first, second))) in parsed.iter().zip(&pairs).enumerate() {
+            prop_assert_eq!(pair.index as usize, i + 1);
+            prop_assert_eq!(&pair.first.to_str(), first);
+            prop_assert_eq!(&pair.second.to_str(), second);
+        }
+    }
+
+    #[test]
+    fn crates_parse_and_move(input in crates()) {
+        parses(2022, 5, &input)?;
+        solves(2022, 5, Part::One, &input)?;
+        solves(2022, 5, Part::Two, &input)?;
+    }
+
+    #[test]
+    fn transcripts_parse(input in transcript()) {
+        parses(2022, 7, &input)?;
+        solves(2022, 7, Part::One, &input)?;
+    }
+
+    #[test]
+    fn monkeys_parse(input in monkeys()) {
+        // Solving is not checked, as worry levels can overflow unless the numbers are picked
+        // as carefully as in the real inputs
+        parses(2022, 11, &input)?;
+    }
+
+    // The parsers are called directly, as the runner would reject most of these inputs for not
+    // having the shape of the day's input before they got to the parser
+    #[test]
+    fn parsers_do_not_panic(input in any::<String>()) {
+        let _ = Day05::parse(&input);
+        let _ = Day07::parse(&input);
+        let _ = Day11::parse(&input);
+        let _ = Day13::parse(&input);
+    }
+
+    #[test]
+    fn parsers_do_not_panic_on_near_misses(
+        packets in "[\\[\\],0-9\n]{0,40}",
+        crates in "[\\[\\] A-C1-3\n]{0,40}(move [0-9] from [0-9] to [0-9]\n){0,3}",
+        transcript in "(\\$ (cd|ls) ?|dir |[0-9]{1,3} )[a-z./]{0,3}(\n[$a-z0-9 ./]{0,10}){0,5}",
+        monkeys in "Monkey [0-9]:\n  Starting items: [0-9, ]{0,8}\n  Operation: new = old [*+] (old|[0-9]{0,2})\n  Test: divisible by [0-9]{0,2}\n(    If (true|false): throw to monkey [0-9]{0,2}\n){0,2}",
+    ) {
+        let _ = Day13::parse(&packets);
+        let _ = Day05::parse(&crates);
+        let _ = Day07::parse(&transcript);
+        let _ = Day11::parse(&monkeys);
+    }
+}
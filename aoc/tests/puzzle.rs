@@ -0,0 +1,124 @@
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use aoc::{
+    answers::{self, Outcome},
+    days,
+    puzzle::{Error, Example, Page},
+    Part,
+};
+
+const COMPLETE: &str = include_str!("pages/2022-01.html");
+const PART_ONE: &str = include_str!("pages/2022-01-part1.html");
+
+fn example_input() -> String {
+    fs::read_to_string(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../2022/day01/test.txt"))
+        .unwrap()
+}
+
+/// A day directory the way the template leaves it.
+fn new_day_dir(name: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    fs::write(dir.join("test.txt"), "").unwrap();
+    fs::write(dir.join("answers.txt"), "# <input file> <part> <answer>\n").unwrap();
+    dir
+}
+
+#[test]
+fn reads_the_example_and_its_answers() {
+    let page = Page::parse(COMPLETE);
+
+    assert!(page.is_complete());
+    assert_eq!(
+        page.example().unwrap(),
+        Example {
+            input: example_input(),
+            answers: [Some("24000".to_string()), Some("45000".to_string())],
+        }
+    );
+}
+
+#[test]
+fn code_blocks_are_read_as_text() {
+    let page = Page::parse(COMPLETE);
+
+    assert_eq!(page.code_blocks(Part::Two), vec!["top < 3 && sum"]);
+}
+
+#[test]
+fn part_two_is_missing_until_part_one_is_solved() {
+    let page = Page::parse(PART_ONE);
+
+    assert!(!page.is_complete());
+    let example = page.example().unwrap();
+    assert_eq!(example.answers, [Some("24000".to_string()), None]);
+}
+
+#[test]
+fn pages_without_a_puzzle_are_rejected() {
+    let page = Page::parse("<html><body><p>Please log in.</p></body></html>");
+
+    assert!(matches!(page.example(), Err(Error::NoDescription)));
+}
+
+#[test]
+fn written_example_passes_the_known_answers() {
+    let dir = new_day_dir("puzzle-written");
+    let example = Page::parse(COMPLETE).example().unwrap();
+
+    assert_eq!(
+        example.write(&dir, false).unwrap(),
+        vec![Part::One, Part::Two]
+    );
+
+    assert_eq!(
+        fs::read_to_string(dir.join("answers.txt")).unwrap(),
+        "# <input file> <part> <answer>\ntest.txt 1 24000\ntest.txt 2 45000\n"
+    );
+    let checks = answers::check_day(days::find(2022, 1).unwrap(), &dir).unwrap();
+    assert_eq!(checks.len(), 2);
+    assert!(checks.iter().all(|c| c.outcome == Outcome::Correct));
+}
+
+#[test]
+fn known_answers_and_examples_are_kept() {
+    let dir = new_day_dir("puzzle-kept");
+    Page::parse(PART_ONE)
+        .example()
+        .unwrap()
+        .write(&dir, false)
+        .unwrap();
+
+    // Solving part 1 makes part 2 show up on the page, only its answer is new
+    let example = Page::parse(COMPLETE).example().unwrap();
+    assert_eq!(example.write(&dir, false).unwrap(), vec![Part::Two]);
+
+    fs::write(dir.join("test.txt"), "1\n2\n").unwrap();
+    assert!(matches!(example.write(&dir, false), Err(Error::Exists(_))));
+}
+
+#[test]
+fn replaced_examples_get_new_answers() {
+    let dir = new_day_dir("puzzle-replaced");
+    fs::write(dir.join("test.txt"), "1\n2\n").unwrap();
+    fs::write(
+        dir.join("answers.txt"),
+        "# <input file> <part> <answer>\ntest.txt 1 3\ninput.txt 1 70000\ntest.txt 2 2\n",
+    )
+    .unwrap();
+
+    let example = Page::parse(COMPLETE).example().unwrap();
+    assert_eq!(
+        example.write(&dir, true).unwrap(),
+        vec![Part::One, Part::Two]
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("test.txt")).unwrap(),
+        example_input()
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("answers.txt")).unwrap(),
+        "# <input file> <part> <answer>\ninput.txt 1 70000\ntest.txt 1 24000\ntest.txt 2 45000\n"
+    );
+}
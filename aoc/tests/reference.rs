@@ -0,0 +1,133 @@
+use aoc::{days, generate, reference, Answer, Day, ParseError, Part, Reference, Solution};
+
+#[test]
+fn solutions_agree_with_their_references() {
+    let with_reference: Vec<&Day> = days::DAYS.iter().filter(|d| d.has_reference()).collect();
+    assert_eq!(with_reference.len(), 3);
+
+    for day in with_reference {
+        let generator = generate::find(day.year, day.day).unwrap();
+        // The reference of 2022 day 15 goes through 18 million positions for every sensor
+        let (seeds, size) = match (day.year, day.day) {
+            (2022, 15) => (0..2, 1),
+            _ => (0..20, generator.size / 10),
+        };
+        let compared = reference::compare(day, generator, seeds.clone(), size)
+            .unwrap_or_else(|disagreement| panic!("{} day {}: {disagreement}", day.year, day.day));
+        assert_eq!(compared, seeds.count());
+    }
+}
+
+#[test]
+fn parts_without_a_reference_are_skipped() {
+    let day = days::find(2022, 21).unwrap();
+    assert!(day.try_solve_reference(Part::One, "root: 5\n").is_none());
+    assert!(day.try_solve_reference(Part::Two, "root: 5\n").is_some());
+    assert!(days::find(2022, 5)
+        .unwrap()
+        .try_solve_reference(Part::One, "")
+        .is_none());
+}
+
+/// Counts the rucksacks with an `a` for part 1, which only agrees with the reference when
+/// the priorities happen to add up to the same.
+struct Miscounting;
+
+impl Solution for Miscounting {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        (input.lines().filter(|l| l.contains('a')).count() as u64).into()
+    }
+
+    fn part_two(_input: Self::Parsed<'_>) -> Answer {
+        todo!()
+    }
+}
+
+impl Reference for Miscounting {
+    fn reference_part_one(input: Self::Parsed<'_>) -> Option<Answer> {
+        Some(
+            days::find(2022, 3)
+                .unwrap()
+                .solve(Part::One, input)
+                .unwrap(),
+        )
+    }
+
+    fn reference_part_two(input: Self::Parsed<'_>) -> Option<Answer> {
+        Some(
+            days::find(2022, 3)
+                .unwrap()
+                .solve(Part::Two, input)
+                .unwrap(),
+        )
+    }
+}
+
+#[test]
+fn first_disagreement_is_reported() {
+    let day = Day::with_reference::<Miscounting>();
+    let generator = generate::find(2022, 3).unwrap();
+
+    let disagreement = reference::compare(&day, generator, 5..10, 2).unwrap_err();
+    assert_eq!(disagreement.part, Part::One);
+    assert_eq!(disagreement.seed, 5);
+    assert_eq!(disagreement.size, 2);
+    assert_eq!(disagreement.input, generator.generate(5, 2));
+    let expected = day.try_solve_reference(Part::One, &disagreement.input);
+    assert_eq!(
+        disagreement.reference.as_ref().ok(),
+        expected.unwrap().as_ref().ok()
+    );
+    assert!(disagreement
+        .to_string()
+        .starts_with("part 1 differs on the input with seed 5 and size 2: the solution gives "));
+}
+
+/// Fails on every input, as does its unfinished reference for part 1.
+struct Broken;
+
+impl Solution for Broken {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(_input: Self::Parsed<'_>) -> Answer {
+        panic!("no rucksacks")
+    }
+
+    fn part_two(_input: Self::Parsed<'_>) -> Answer {
+        todo!()
+    }
+}
+
+impl Reference for Broken {
+    fn reference_part_one(_input: Self::Parsed<'_>) -> Option<Answer> {
+        todo!()
+    }
+}
+
+#[test]
+fn inputs_both_solvers_fail_on_are_disagreements() {
+    let day = Day::with_reference::<Broken>();
+    let generator = generate::find(2022, 3).unwrap();
+
+    let disagreement = reference::compare(&day, generator, 0..3, 2).unwrap_err();
+    assert_eq!(disagreement.part, Part::One);
+    assert_eq!(disagreement.seed, 0);
+    assert!(disagreement.optimized.is_err());
+    assert!(disagreement.reference.is_err());
+}
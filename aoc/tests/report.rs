@@ -0,0 +1,100 @@
+use std::{path::Path, time::Duration};
+
+use aoc::{
+    days,
+    parse::ParseError,
+    report::{self, Options, Status},
+    Answer, Day, Part, Solution,
+};
+
+fn run(days: &[(u16, u8)], timeout: Duration) -> report::Report {
+    let days: Vec<_> = days
+        .iter()
+        .map(|&(year, day)| days::find(year, day).unwrap())
+        .collect();
+    run_days(&days, timeout)
+}
+
+fn run_days(days: &[&'static Day], timeout: Duration) -> report::Report {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    report::run(
+        days,
+        &root,
+        &Options {
+            threads: 4,
+            timeout,
+        },
+    )
+}
+
+/// 2022 day 15, with a part 2 that takes far longer than any timeout in the tests.
+struct Slow;
+
+impl Solution for Slow {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        days::find(2022, 15)
+            .unwrap()
+            .solve(Part::One, input)
+            .unwrap()
+    }
+
+    fn part_two(_input: Self::Parsed<'_>) -> Answer {
+        std::thread::sleep(Duration::from_secs(60));
+        unreachable!("the part should have timed out")
+    }
+}
+
+#[test]
+fn reports_every_part_in_order() {
+    let report = run(&[(2022, 6), (2022, 1)], Duration::from_secs(60));
+
+    let order: Vec<_> = report.runs.iter().map(|run| (run.day, run.part)).collect();
+    assert_eq!(
+        order,
+        [
+            (1, Part::One),
+            (1, Part::Two),
+            (6, Part::One),
+            (6, Part::Two)
+        ]
+    );
+    assert_eq!(report.runs[0].answer, Some(Answer::Integer(74198)));
+    assert!(report.runs.iter().all(|run| run.elapsed.is_some()));
+    assert_eq!(report.counts(), [("correct", 4)]);
+    assert!(!report.has_failures());
+}
+
+#[test]
+fn unfinished_parts_do_not_stop_the_others() {
+    let report = run(&[(2022, 16), (2022, 2)], Duration::from_secs(60));
+
+    assert_eq!(report.counts(), [("correct", 2), ("todo", 2)]);
+    assert!(matches!(report.runs[2].status, Status::Todo));
+    assert!(!report.has_failures());
+}
+
+#[test]
+fn slow_parts_time_out() {
+    // Part 1 of 2022 day 10 takes well under a millisecond
+    static SLOW: Day = Day::of::<Slow>();
+    let report = run_days(
+        &[&SLOW, days::find(2022, 10).unwrap()],
+        Duration::from_millis(500),
+    );
+
+    let day15 = &report.runs[3];
+    assert!(matches!(day15.status, Status::TimedOut));
+    assert_eq!(day15.answer, None);
+    assert!(matches!(report.runs[0].status, Status::Correct));
+    assert!(report.has_failures());
+    assert!(report.total < Duration::from_secs(2));
+}
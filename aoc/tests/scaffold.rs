@@ -0,0 +1,190 @@
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc::{
+    days,
+    scaffold::{Error, NewDay},
+};
+
+const MANIFEST: &str = r#"[workspace]
+members = [
+    "aoc",
+    "2022/day01",
+    "2022/day08",
+]
+"#;
+
+const DAYS: &str = "pub mod year2022;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<year2022::Day01>(),
+    Day::of::<year2022::Day08>(),
+];
+";
+
+const YEAR: &str = "mod day01;
+mod day08;
+
+pub use day01::Day01;
+pub use day08::Day08;
+";
+
+/// Days registered with every constructor of `Day`, like the real registry.
+const MIXED_DAYS: &str = "pub mod year2022;
+
+pub const DAYS: &[Day] = &[
+    Day::streaming::<year2022::Day01>(),
+    Day::of::<year2022::Day08>(),
+    Day::with_reference::<year2022::Day21>(),
+];
+";
+
+fn repository(name: &str) -> PathBuf {
+    repository_with(name, DAYS)
+}
+
+fn repository_with(name: &str, days: &str) -> PathBuf {
+    let root = common::temp_dir(name);
+    fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+    fs::create_dir_all(root.join("aoc/src/days/year2022")).unwrap();
+    fs::write(root.join("aoc/src/days/mod.rs"), days).unwrap();
+    fs::write(root.join("aoc/src/days/year2022/mod.rs"), YEAR).unwrap();
+    root
+}
+
+fn read(root: &Path, path: &str) -> String {
+    fs::read_to_string(root.join(path)).unwrap()
+}
+
+#[test]
+fn creates_the_day_from_the_template() {
+    let root = repository("creates");
+    let dir = NewDay { year: 2022, day: 5 }.create(&root).unwrap();
+
+    assert_eq!(dir, root.join("2022/day05"));
+    assert_eq!(read(&dir, "test.txt"), "");
+    let main = read(&dir, "src/main.rs");
+    assert!(main.contains("aoc::handle_day(2022, 5);"), "{main}");
+    let lib = read(&dir, "src/lib.rs");
+    assert!(lib.contains("use day_2022_05::parse;"), "{lib}");
+    assert!(lib.contains("mod solution;"), "{lib}");
+    let manifest = read(&dir, "Cargo.toml");
+    assert!(manifest.contains("name = \"day_2022_05\""), "{manifest}");
+    let dockerfile = read(&dir, "Dockerfile");
+    assert!(
+        dockerfile.contains("COPY 2022/day05/input.txt"),
+        "{dockerfile}"
+    );
+
+    let registry = read(&root, "aoc/src/days/year2022/day05.rs");
+    assert!(registry.contains("impl Solution for Day05"), "{registry}");
+    assert!(registry.contains("const DAY: u8 = 5;"), "{registry}");
+    assert!(
+        registry.contains("\"../../../../2022/day05/src/solution.rs\""),
+        "{registry}"
+    );
+    assert!(!registry.contains("{{"), "{registry}");
+}
+
+#[test]
+fn registers_the_day_in_order() {
+    let root = repository("registers");
+    NewDay { year: 2022, day: 5 }.create(&root).unwrap();
+
+    let manifest = read(&root, "Cargo.toml");
+    assert!(manifest.contains("    \"2022/day01\",\n    \"2022/day05\",\n    \"2022/day08\","));
+
+    let year = read(&root, "aoc/src/days/year2022/mod.rs");
+    assert!(
+        year.contains("mod day01;\nmod day05;\nmod day08;"),
+        "{year}"
+    );
+    assert!(
+        year.contains("pub use day05::Day05;\npub use day08::Day08;"),
+        "{year}"
+    );
+
+    let days = read(&root, "aoc/src/days/mod.rs");
+    assert!(
+        days.contains("    Day::of::<year2022::Day05>(),\n    Day::of::<year2022::Day08>(),"),
+        "{days}"
+    );
+}
+
+#[test]
+fn adds_a_module_for_a_new_year() {
+    let root = repository("new-year");
+    NewDay { year: 2021, day: 3 }.create(&root).unwrap();
+
+    let manifest = read(&root, "Cargo.toml");
+    assert!(manifest.contains("    \"2021/day03\",\n    \"2022/day01\","));
+
+    let year = read(&root, "aoc/src/days/year2021/mod.rs");
+    assert!(year.contains("mod day03;"), "{year}");
+    assert!(year.contains("pub use day03::Day03;"), "{year}");
+
+    let days = read(&root, "aoc/src/days/mod.rs");
+    assert!(
+        days.contains("pub mod year2021;\npub mod year2022;"),
+        "{days}"
+    );
+    assert!(
+        days.contains("    Day::of::<year2021::Day03>(),\n    Day::of::<year2022::Day01>(),"),
+        "{days}"
+    );
+}
+
+#[test]
+fn registers_between_days_with_other_constructors() {
+    let root = repository_with("mixed", MIXED_DAYS);
+    NewDay { year: 2022, day: 5 }.create(&root).unwrap();
+    NewDay {
+        year: 2022,
+        day: 22,
+    }
+    .create(&root)
+    .unwrap();
+    NewDay { year: 2023, day: 1 }.create(&root).unwrap();
+    NewDay { year: 2021, day: 3 }.create(&root).unwrap();
+
+    let days = read(&root, "aoc/src/days/mod.rs");
+    let entries: Vec<&str> = days
+        .lines()
+        .filter(|l| l.starts_with("    Day::"))
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            "    Day::of::<year2021::Day03>(),",
+            "    Day::streaming::<year2022::Day01>(),",
+            "    Day::of::<year2022::Day05>(),",
+            "    Day::of::<year2022::Day08>(),",
+            "    Day::with_reference::<year2022::Day21>(),",
+            "    Day::of::<year2022::Day22>(),",
+            "    Day::of::<year2023::Day01>(),",
+        ]
+    );
+}
+
+#[test]
+fn refuses_to_overwrite_a_day() {
+    let root = repository("refuses");
+    NewDay { year: 2022, day: 5 }.create(&root).unwrap();
+    let days = read(&root, "aoc/src/days/mod.rs");
+
+    let err = NewDay { year: 2022, day: 5 }.create(&root).unwrap_err();
+    assert!(matches!(err, Error::Exists(path) if path == root.join("2022/day05")));
+    assert_eq!(read(&root, "aoc/src/days/mod.rs"), days);
+}
+
+#[test]
+fn the_registry_is_sorted() {
+    let days: Vec<(u16, u8)> = days::DAYS.iter().map(|d| (d.year, d.day)).collect();
+    let mut sorted = days.clone();
+    sorted.sort();
+    assert_eq!(days, sorted);
+}
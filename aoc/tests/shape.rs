@@ -0,0 +1,105 @@
+use std::{borrow::Cow, fs, path::Path};
+
+use aoc::{
+    answers::{self, Answers, Outcome},
+    days,
+    shape::{normalize, Shape},
+    Part,
+};
+
+#[test]
+fn normalizes_line_endings_and_trailing_whitespace() {
+    assert_eq!(
+        normalize("\u{feff}a b  \r\n  c\t\r\n\r\nd\r\n\r\n\n"),
+        "a b\n  c\n\nd\n"
+    );
+    assert_eq!(normalize("1\n2"), "1\n2\n");
+    assert_eq!(normalize(" \n\n"), "");
+}
+
+#[test]
+fn normalized_inputs_are_not_copied() {
+    assert!(matches!(normalize("    [D]\n 1\n"), Cow::Borrowed(_)));
+    assert!(matches!(normalize(""), Cow::Borrowed("")));
+}
+
+#[test]
+fn lines_have_no_blanks() {
+    assert_eq!(Shape::Lines.check("1\n2\n"), Ok(()));
+
+    let err = Shape::Lines.check("1\n\n2\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+    assert!(Shape::Lines.check("").is_err());
+    assert_eq!(Shape::Any.check(""), Ok(()));
+}
+
+#[test]
+fn grids_are_rectangular() {
+    assert_eq!(Shape::Grid.check("abc\ndef\n"), Ok(()));
+
+    let longer = Shape::Grid.check("abc\ndefg\n").unwrap_err();
+    assert_eq!((longer.line, longer.column), (2, 4));
+    assert_eq!(longer.expected, "a row of 3 characters");
+
+    let shorter = Shape::Grid.check("abc\nde\nfgh\n").unwrap_err();
+    assert_eq!((shorter.line, shorter.column), (2, 3));
+}
+
+#[test]
+fn blocks_are_separated_by_single_blank_lines() {
+    let blocks = Shape::Blocks { lines: None };
+    assert_eq!(blocks.check("1\n2\n\n3\n"), Ok(()));
+
+    let err = blocks.check("1\n\n\n3\n").unwrap_err();
+    assert_eq!(err.line, 3);
+}
+
+#[test]
+fn blocks_have_the_given_number_of_lines() {
+    let pairs = Shape::Blocks { lines: Some(2) };
+    assert_eq!(pairs.check("[1]\n[2]\n\n[3]\n[4]\n"), Ok(()));
+
+    let short = pairs.check("[1]\n\n[3]\n[4]\n").unwrap_err();
+    assert_eq!(
+        (short.line, short.expected.as_str()),
+        (2, "blocks of 2 lines")
+    );
+
+    let long = pairs.check("[1]\n[2]\n[3]\n").unwrap_err();
+    assert_eq!(
+        (long.line, long.expected.as_str()),
+        (3, "a blank line between blocks")
+    );
+
+    let unfinished = pairs.check("[1]\n[2]\n\n[3]\n").unwrap_err();
+    assert_eq!((unfinished.line, unfinished.column), (4, 4));
+}
+
+/// Every day gets the same answers for its example when it was saved on Windows, with CRLF line
+/// endings, trailing spaces and no final newline.
+#[test]
+fn examples_saved_on_windows_give_the_same_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for day in days::DAYS {
+        let dir = root.join(day.dir());
+        let known = Answers::load(&dir.join("answers.txt")).unwrap();
+        let Ok(example) = fs::read_to_string(dir.join("test.txt")) else {
+            continue;
+        };
+        let lines: Vec<String> = example.lines().map(|l| format!("{l}  ")).collect();
+        let saved = lines.join("\r\n");
+
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = known.get("test.txt", part) else {
+                continue;
+            };
+            let outcome = answers::check(day, part, &saved, expected);
+            assert!(
+                matches!(outcome, Outcome::Correct | Outcome::Todo),
+                "{} day {} {part}: {outcome}",
+                day.year,
+                day.day
+            );
+        }
+    }
+}
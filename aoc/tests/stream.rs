@@ -0,0 +1,135 @@
+use std::{
+    fs,
+    io::{self, BufReader, Cursor, Read},
+    path::Path,
+};
+
+use aoc::{days, stream, Answer, Part};
+
+fn stream(year: u16, day: u8, part: Part, input: impl Read) -> Result<Answer, stream::Error> {
+    let day = days::find(year, day).unwrap();
+    let mut reader = BufReader::new(input);
+    day.solve_stream(part, &mut reader).unwrap()
+}
+
+fn parse_error(year: u16, day: u8, input: &str) -> aoc::ParseError {
+    match stream(year, day, Part::One, input.as_bytes()) {
+        Err(stream::Error::Parse(err)) => err,
+        result => panic!("expected a parse error, got {result:?}"),
+    }
+}
+
+/// Repeats a record without keeping the repeated input in memory.
+struct Repeat {
+    record: &'static [u8],
+    times: usize,
+    at: usize,
+}
+
+impl Read for Repeat {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() && self.times > 0 {
+            let rest = &self.record[self.at..];
+            let n = rest.len().min(buf.len() - written);
+            buf[written..written + n].copy_from_slice(&rest[..n]);
+            written += n;
+            self.at += n;
+            if self.at == self.record.len() {
+                self.at = 0;
+                self.times -= 1;
+            }
+        }
+        Ok(written)
+    }
+}
+
+#[test]
+fn streamed_answers_match() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let streaming: Vec<_> = days::DAYS.iter().filter(|d| d.can_stream()).collect();
+    assert_eq!(streaming.len(), 5);
+
+    for day in streaming {
+        for file in ["test.txt", "input.txt"] {
+            let Ok(input) = fs::read_to_string(root.join(day.dir()).join(file)) else {
+                continue;
+            };
+            for part in [Part::One, Part::Two] {
+                let expected = day.solve(part, &input).unwrap();
+                let answer = stream(day.year, day.day, part, input.as_bytes()).unwrap();
+                assert_eq!(
+                    answer, expected,
+                    "{} day {} {part} {file}",
+                    day.year, day.day
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn inputs_are_cleaned_up_while_reading() {
+    let clean = "1000\n2000\n\n3000\n\n500\n500\n\n100\n";
+    let saved = "\u{feff}1000 \r\n2000\r\n\r\n3000\r\n\r\n500\r\n500\r\n\r\n100\r\n\r\n\r\n";
+
+    for part in [Part::One, Part::Two] {
+        assert_eq!(
+            stream(2022, 1, part, saved.as_bytes()).unwrap(),
+            stream(2022, 1, part, clean.as_bytes()).unwrap(),
+        );
+    }
+    assert_eq!(
+        stream(2022, 6, Part::One, "\u{feff}abcdefg\r\n".as_bytes()).unwrap(),
+        Answer::from(4)
+    );
+}
+
+#[test]
+fn errors_point_at_the_line() {
+    let err = parse_error(2022, 4, "2-4,6-8\r\n2-3,4x5\r\n");
+    assert_eq!((err.line, err.column), (2, 8));
+    assert_eq!(err.source_line, "2-3,4x5");
+
+    let err = parse_error(2022, 2, "A Y\n\n\nB X\n");
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.expected, "one of A, B, C");
+
+    // Parts that stop once the records end still report the error
+    let err = parse_error(2022, 10, "noop\naddx 3\naddx x\n");
+    assert_eq!((err.line, err.column), (3, 6));
+}
+
+#[test]
+fn errors_in_long_lines_show_an_excerpt() {
+    let input = format!("{}Dabc\n", "ab".repeat(5000));
+    let err = parse_error(2022, 6, &input);
+
+    assert_eq!((err.line, err.column), (1, 10001));
+    assert_eq!(err.found(), "`D`");
+    assert!(
+        err.to_string()
+            .ends_with("1 | ...Dabc\n  |    ^ expected a lowercase letter"),
+        "{err}"
+    );
+}
+
+#[test]
+fn large_inputs_are_not_read_into_memory() {
+    let times = 200_000;
+    let rounds = Repeat {
+        record: b"A Y\nB X\nC Z\n",
+        times,
+        at: 0,
+    };
+
+    let answer = stream(2022, 2, Part::One, rounds).unwrap();
+    assert_eq!(answer, Answer::from(times * 15));
+}
+
+#[test]
+fn days_that_need_the_whole_input_do_not_stream() {
+    let day = days::find(2022, 5).unwrap();
+    assert!(!day.can_stream());
+    assert!(day.solve_stream(Part::One, &mut Cursor::new("")).is_none());
+}
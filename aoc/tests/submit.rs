@@ -0,0 +1,90 @@
+#![cfg(feature = "download_input")]
+
+mod common;
+
+use aoc::{
+    client::{parse_verdict, Client, Error},
+    ledger::Verdict,
+    Part,
+};
+use common::{StandIn, SESSION};
+
+fn page(text: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><body><main><article><p>{text}</p></article></main></body></html>"
+    )
+}
+
+const CORRECT: &str = "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2022/about\">about page</a>. Please wait one minute before trying again. <a href=\"/2022/day/14\">[Return to Day 14]</a>";
+const TOO_LOW: &str = "That's not the right answer; your answer is too low.  Please wait one minute before trying again.";
+const WRONG: &str =
+    "That's not the right answer.  If you're stuck, make sure you're using the full input data.";
+const TOO_RECENT: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 38s left to wait. <a href=\"/2022/day/14\">[Return to Day 14]</a>";
+const WRONG_LEVEL: &str = "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/14\">[Return to Day 14]</a>";
+
+#[test]
+fn parses_verdicts() {
+    assert_eq!(parse_verdict(&page(CORRECT)), Some(Verdict::Correct));
+    assert_eq!(parse_verdict(&page(TOO_HIGH)), Some(Verdict::TooHigh));
+    assert_eq!(parse_verdict(&page(TOO_LOW)), Some(Verdict::TooLow));
+    assert_eq!(parse_verdict(&page(WRONG)), Some(Verdict::Wrong));
+    assert_eq!(
+        parse_verdict(&page(TOO_RECENT)),
+        Some(Verdict::RateLimited {
+            wait: Some("38s".to_string())
+        })
+    );
+    assert_eq!(
+        parse_verdict(&page(WRONG_LEVEL)),
+        Some(Verdict::AlreadySolved)
+    );
+    assert_eq!(parse_verdict(&page("Something else")), None);
+}
+
+#[test]
+fn submits_answer() {
+    let server = StandIn::start(|request| match request.path.as_str() {
+        "/2022/day/14/answer" if request.method == "POST" => {
+            if request.body == "level=2&answer=27601" {
+                (200, page(CORRECT))
+            } else {
+                (200, page(TOO_LOW))
+            }
+        }
+        _ => (404, "404 Not Found".to_string()),
+    });
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    assert_eq!(
+        client.submit(2022, 14, Part::Two, "27601").unwrap(),
+        Verdict::Correct
+    );
+    assert_eq!(
+        client.submit(2022, 14, Part::One, "27601").unwrap(),
+        Verdict::TooLow
+    );
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn submit_with_expired_session() {
+    let server = StandIn::start(|_| (200, page(CORRECT)));
+    let client = Client::new(&server.url, "expired").unwrap();
+
+    assert!(matches!(
+        client.submit(2022, 14, Part::One, "1"),
+        Err(Error::ExpiredSession)
+    ));
+}
+
+#[test]
+fn unknown_response() {
+    let server = StandIn::start(|_| (200, page("Nothing to see here")));
+    let client = Client::new(&server.url, SESSION).unwrap();
+
+    assert!(matches!(
+        client.submit(2022, 14, Part::One, "1"),
+        Err(Error::UnknownResponse(_))
+    ));
+}
@@ -0,0 +1,141 @@
+mod common;
+
+use std::{fs, path::Path, time::Duration};
+
+use aoc::{
+    days,
+    visual::{
+        self,
+        export::{self, Format},
+        Cell, Color, Frame, Options,
+    },
+    Part,
+};
+use aoc_utils::{Grid, Point};
+
+fn frame(width: usize, height: usize, glyph: char) -> Frame {
+    Frame::new(Grid::new(width, height, Cell::new(glyph, Color::WHITE)))
+}
+
+#[test]
+fn records_nothing_unless_recording() {
+    let mut drawn = false;
+    visual::record(|| {
+        drawn = true;
+        frame(1, 1, '#')
+    });
+
+    assert!(!drawn);
+    assert!(!visual::is_recording());
+}
+
+#[test]
+fn keeps_every_nth_frame_up_to_the_limit() {
+    let options = Options { every: 3, limit: 2 };
+    let (answer, frames) = visual::recording(options, || {
+        for i in 0..10 {
+            visual::record(|| frame(i + 1, 1, '#'));
+        }
+        42
+    });
+
+    assert_eq!(answer, 42);
+    let widths: Vec<usize> = frames.iter().map(|f| f.cells.width()).collect();
+    assert_eq!(widths, [1, 4]);
+    assert!(!visual::is_recording());
+}
+
+#[test]
+fn lines_frames_up_by_their_origin() {
+    let frames = [frame(2, 1, 'a'), frame(1, 2, 'b').at(Point::new(-1, -1))];
+
+    assert_eq!(
+        export::text(&frames),
+        "frame 1/2\n   \n aa\n\nframe 2/2\nb  \nb  \n\n"
+    );
+}
+
+#[test]
+fn frames_from_points_cover_just_the_points() {
+    let frame = Frame::from_points(
+        [
+            (Point::new(3, -1), Cell::new('a', Color::RED)),
+            (Point::new(1, 0), Cell::new('b', Color::RED)),
+        ],
+        Cell::new('.', Color::BLACK),
+    );
+
+    assert_eq!(frame.origin, Point::new(1, -1));
+    assert_eq!(frame.to_string(), "..a\nb..");
+}
+
+#[test]
+fn encodes_images() {
+    let frames = [frame(3, 2, '#'), frame(3, 2, '.')];
+    let canvas = export::canvas(&frames);
+
+    let ppm = export::ppm(&canvas[0], 2);
+    assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+    assert_eq!(ppm.len(), "P6\n6 4\n255\n".len() + 6 * 4 * 3);
+
+    let png = export::png(&canvas[0], 2).unwrap();
+    let reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+    assert_eq!((reader.info().width, reader.info().height), (6, 4));
+
+    let options = export::Options {
+        scale: 2,
+        delay: Duration::from_millis(100),
+    };
+    let gif = export::gif(&frames, &options).unwrap();
+    let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+    let mut count = 0;
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!((frame.width, frame.height, frame.delay), (6, 4, 10));
+        count += 1;
+    }
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn saves_a_file_per_frame() {
+    let dir = common::temp_dir("visual-frames");
+    let frames = [frame(1, 1, '#'), frame(1, 1, '.')];
+
+    export::save(
+        &frames,
+        Format::Ppm,
+        Some(&dir),
+        &export::Options::default(),
+    )
+    .unwrap();
+    assert!(dir.join("frame_0001.ppm").exists());
+    assert!(dir.join("frame_0002.ppm").exists());
+
+    let err = export::save(&frames, Format::Gif, None, &export::Options::default()).unwrap_err();
+    assert!(matches!(err, export::Error::NoOutput(Format::Gif)));
+}
+
+#[test]
+fn days_record_their_simulation() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for (year, day, part) in [
+        (2022, 5, Part::One),
+        (2022, 9, Part::Two),
+        (2022, 12, Part::One),
+        (2022, 14, Part::One),
+    ] {
+        let solution = days::find(year, day).unwrap();
+        let input = fs::read_to_string(root.join(solution.dir()).join("test.txt")).unwrap();
+
+        let (answer, frames) =
+            visual::recording(Options::default(), || solution.try_solve(part, &input));
+        assert!(answer.is_ok(), "{year} day {day}");
+        assert!(!frames.is_empty(), "{year} day {day} recorded no frames");
+    }
+
+    let solution = days::find(2022, 14).unwrap();
+    let input = fs::read_to_string(root.join(solution.dir()).join("test.txt")).unwrap();
+    let (_, frames) = visual::recording(Options::default(), || solution.solve(Part::One, &input));
+    let last = frames.last().unwrap().to_string();
+    assert_eq!(last.matches('o').count(), 24, "{last}");
+}
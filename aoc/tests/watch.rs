@@ -0,0 +1,89 @@
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use aoc::{answers::Outcome, days, watch::Table};
+
+/// A copy of 2022 day 1 with its example and the given known answers.
+fn day_dir(name: &str, answers: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    let example = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../2022/day01/test.txt");
+    fs::copy(example, dir.join("test.txt")).unwrap();
+    fs::write(dir.join("answers.txt"), answers).unwrap();
+    dir
+}
+
+#[test]
+fn compares_with_the_known_answers() {
+    let dir = day_dir("watch-compare", "test.txt 1 24000\ntest.txt 2 1\n");
+    let day = days::find(2022, 1).unwrap();
+
+    let table = Table::run(day, &dir).unwrap();
+
+    assert_eq!(table.files, vec![dir.join("test.txt")]);
+    assert_eq!(table.rows[0][0].to_string(), "24000 ok");
+    assert_eq!(table.rows[1][0].to_string(), "45000 WRONG, expected 1");
+    assert!(table.has_failures());
+}
+
+#[test]
+fn shows_the_inputs_side_by_side() {
+    let dir = day_dir("watch-side-by-side", "test.txt 1 24000\nsmall.txt 1 4\n");
+    fs::write(dir.join("small.txt"), "1\n2\n\n3\n\n4\n").unwrap();
+    let day = days::find(2022, 1).unwrap();
+
+    let table = Table::run(day, &dir).unwrap();
+
+    assert!(!table.has_failures());
+    assert_eq!(
+        table.to_string(),
+        "          test.txt  small.txt\n\
+         part 1    24000 ok  4 ok\n\
+         part 2    45000     10\n"
+    );
+}
+
+#[test]
+fn unparsable_inputs_are_failures() {
+    let dir = day_dir("watch-invalid", "");
+    fs::write(dir.join("test.txt"), "1\nten\n").unwrap();
+    let day = days::find(2022, 1).unwrap();
+
+    let table = Table::run(day, &dir).unwrap();
+
+    assert!(matches!(table.rows[0][0].outcome, Outcome::Invalid(_)));
+    assert!(table.has_failures());
+    assert!(table.to_string().contains("test.txt:2:1"));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn notices_changed_files() {
+    use aoc::watch::Watcher;
+
+    let dir = day_dir("watch-changes", "");
+    fs::create_dir(dir.join("src")).unwrap();
+    fs::create_dir(dir.join("inputs")).unwrap();
+    let mut watcher = Watcher::new(&dir).unwrap();
+
+    fs::write(dir.join("notes.txt"), "not watched").unwrap();
+    fs::write(dir.join("inputs/notes.md"), "not watched").unwrap();
+    fs::write(dir.join("input.txt"), "1\n").unwrap();
+    fs::write(dir.join("inputs/alice.txt"), "2\n").unwrap();
+    // Saved the way many editors do, by replacing the file
+    fs::write(dir.join("src/.solution.rs.swp"), "fn main() {}").unwrap();
+    fs::rename(
+        dir.join("src/.solution.rs.swp"),
+        dir.join("src/solution.rs"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        watcher.wait().unwrap(),
+        vec![
+            PathBuf::from("input.txt"),
+            PathBuf::from("inputs/alice.txt"),
+            PathBuf::from("src/solution.rs")
+        ]
+    );
+}
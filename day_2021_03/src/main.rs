@@ -1,10 +0,0 @@
-use generate_aoc_day::generate_day;
-
-mod solution;
-
-// Replace with desired year/day
-generate_day!(2021, 3);
-
-fn main() {
-    handle_day();
-}
@@ -0,0 +1,28 @@
+#[allow(dead_code, unreachable_code)]
+#[path = "../../../../{{dir}}/src/solution.rs"]
+mod solution;
+
+use crate::{parse::ParseError, Answer, Shape, Solution};
+
+pub struct {{type}};
+
+impl Solution for {{type}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    // Lines, Grid or Blocks once the layout of the input is known
+    const SHAPE: Shape = Shape::Any;
+
+    type Parsed<'a> = solution::Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        solution::parse(input)
+    }
+
+    fn part_one(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_one(input)
+    }
+
+    fn part_two(input: Self::Parsed<'_>) -> Answer {
+        solution::solve_part_two(input)
+    }
+}
@@ -0,0 +1,13 @@
+//! Day {{day}} of {{year}}.
+//!
+//! ```
+//! use {{package}}::parse;
+//!
+//! parse(include_str!("../test.txt")).unwrap();
+//! ```
+
+#![warn(missing_docs)]
+
+mod solution;
+
+pub use solution::*;
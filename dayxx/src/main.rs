@@ -1,12 +1,3 @@
-#![feature(test)]
-
-use generate_aoc_day::generate_day;
-
-mod solution;
-
-// Replace with desired year/day
-generate_day!(2022, x);
-
 fn main() {
-    handle_day();
+    aoc::handle_day({{year}}, {{day}});
 }
@@ -1,11 +1,19 @@
-pub fn parse<'a>(input: &str) -> impl Iterator<Item = u8> + 'a {
-    todo!("Parse is not yet implemented");
+use aoc::{parse::ParseError, Answer};
+
+/// What the input is parsed into, both parts take it.
+pub type Parsed<'a> = Vec<&'a str>;
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Parsed<'_>, ParseError> {
+    Ok(input.lines().collect())
 }
 
-pub fn solve_part_one<'a>(input: impl Iterator<Item = u8>) -> String {
+/// Not solved yet.
+pub fn solve_part_one(_input: Parsed<'_>) -> Answer {
     todo!("Part one is not yet implemented");
 }
 
-pub fn solve_part_two<'a>(input: impl Iterator<Item = u8>) -> String {
+/// Not solved yet.
+pub fn solve_part_two(_input: Parsed<'_>) -> Answer {
     todo!("Part two is not yet implemented");
 }
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc::{days::year2022::Day07, Solution};
+use libfuzzer_sys::fuzz_target;
+
+// Any input may be rejected, but the parser must not panic on it. The parser is called directly,
+// as the runner would clean up most inputs and check their shape before the parser sees them
+fuzz_target!(|input: &str| {
+    let _ = Day07::parse(input);
+});
@@ -0,0 +1,28 @@
+#![no_main]
+
+use aoc::{days::year2022::Day13, Solution};
+use libfuzzer_sys::fuzz_target;
+
+// Any input may be rejected, but the parser must not panic on it, and the packets it accepts
+// have to read the same when written out and parsed again
+fuzz_target!(|input: &str| {
+    let Ok(pairs) = Day13::parse(input) else {
+        return;
+    };
+    let pairs: Vec<_> = pairs.collect();
+
+    let written: Vec<String> = pairs
+        .iter()
+        .map(|pair| format!("{}\n{}", pair.first.to_str(), pair.second.to_str()))
+        .collect();
+    let written = written.join("\n\n");
+    let reparsed: Vec<_> = Day13::parse(&written)
+        .expect("written packets parse")
+        .collect();
+
+    assert_eq!(reparsed.len(), pairs.len());
+    for (pair, again) in pairs.iter().zip(&reparsed) {
+        assert_eq!(pair.first, again.first);
+        assert_eq!(pair.second, again.second);
+    }
+});
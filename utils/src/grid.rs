@@ -0,0 +1,234 @@
+//! A rectangular grid of cells, stored row by row.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::Point;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Why a grid could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error<'a, E> {
+    /// A line is not as wide as the first one. `at` points at the first extra character, or the
+    /// end of the line if it is too short.
+    Width { at: &'a str, width: usize },
+    /// The error returned for a cell.
+    Cell(E),
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid with each cell set to what `cell` returns for its position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point<usize>) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one cell per character, with each line as a row. `cell` gets the
+    /// character as a slice of `input`, so errors can point at it.
+    pub fn parse<'a, E>(
+        input: &'a str,
+        mut cell: impl FnMut(&'a str, Point<usize>) -> Result<T, E>,
+    ) -> Result<Self, Error<'a, E>> {
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut x = 0;
+            for (i, c) in line.char_indices() {
+                if x == width {
+                    return Err(Error::Width {
+                        at: &line[i..],
+                        width,
+                    });
+                }
+                let value = cell(&line[i..i + c.len_utf8()], Point::new(x, y));
+                cells.push(value.map_err(Error::Cell)?);
+                x += 1;
+            }
+            if x < width {
+                return Err(Error::Width {
+                    at: &line[line.len()..],
+                    width,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The positions to the left, above, to the right and below that are inside the grid.
+    pub fn neighbours(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+        OFFSETS
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// The positions around a position, diagonals included, that are inside the grid.
+    pub fn neighbours_with_diagonals(
+        &self,
+        point: Point<usize>,
+    ) -> impl Iterator<Item = Point<usize>> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        OFFSETS
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    fn offset(&self, point: Point<usize>, (dx, dy): (isize, isize)) -> Option<Point<usize>> {
+        let moved = Point::new(
+            point.x.checked_add_signed(dx)?,
+            point.y.checked_add_signed(dy)?,
+        );
+        self.contains(moved).then_some(moved)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid with a character per cell, for cells that are not [`Display`] or should
+    /// be drawn differently.
+    pub fn display_with<F: Fn(&T) -> char>(&self, draw: F) -> DisplayWith<'_, T, F> {
+        DisplayWith { grid: self, draw }
+    }
+}
+
+/// Panics if the position is outside the grid.
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+/// Panics if the position is outside the grid.
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {width}x{height} grid"))
+    }
+}
+
+/// One line per row, with the cells of a row written one after another.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// See [`Grid::display_with`].
+pub struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    draw: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for DisplayWith<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", (self.draw)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
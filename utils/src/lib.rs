@@ -0,0 +1,9 @@
+//! Building blocks that come up in many puzzles: grids, points and number theory.
+
+pub mod grid;
+pub mod math;
+pub mod point;
+
+pub use grid::Grid;
+pub use math::Integer;
+pub use point::Point;
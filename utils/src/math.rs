@@ -0,0 +1,72 @@
+//! Number theory on any of the primitive integer types.
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign},
+};
+
+/// The primitive integer types, so points and helpers work with any width.
+pub trait Integer:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The distance between two numbers, which can't overflow for unsigned types.
+    fn distance(self, other: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($distance:expr; $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                #[inline(always)]
+                fn distance(self, other: Self) -> Self {
+                    $distance(self, other)
+                }
+            }
+        )*
+    };
+}
+
+integer!(|a: Self, b: Self| a.abs_diff(b); u8, u16, u32, u64, u128, usize);
+integer!(|a: Self, b: Self| (a - b).abs(); i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor, `0` only if both numbers are `0`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        T::ZERO
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// The least common multiple of all the numbers, `1` if there are none.
+pub fn lcm_of<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
@@ -0,0 +1,61 @@
+use aoc_utils::{grid::Error, Grid, Point};
+
+fn digits(input: &str) -> Result<Grid<u8>, Error<'_, &str>> {
+    Grid::parse(input, |c, _| c.parse::<u8>().map_err(|_| c))
+}
+
+#[test]
+fn parses_a_cell_per_character() {
+    let grid = digits("123\n456\n").unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 0)], 3);
+    assert_eq!(grid[Point::new(0, 1)], 4);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.row(1), &[4, 5, 6]);
+}
+
+#[test]
+fn reports_lines_of_the_wrong_width() {
+    let input = "123\n4567\n89\n";
+
+    let Err(Error::Width { at, width }) = digits(input) else {
+        panic!("expected a width error");
+    };
+    assert_eq!((at, width), ("7", 3));
+    assert_eq!(at.as_ptr(), input[7..].as_ptr());
+
+    let Err(Error::Width { at, .. }) = digits("123\n45\n") else {
+        panic!("expected a width error");
+    };
+    assert_eq!(at, "");
+}
+
+#[test]
+fn passes_on_cell_errors() {
+    assert_eq!(digits("12\n3x\n"), Err(Error::Cell("x")));
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = Grid::new(3, 3, ());
+
+    let corner: Vec<_> = grid.neighbours(Point::new(0, 0)).collect();
+    assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+
+    assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbours_with_diagonals(Point::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours_with_diagonals(Point::new(2, 2)).count(), 3);
+}
+
+#[test]
+fn displays_a_line_per_row() {
+    let grid = Grid::from_fn(3, 2, |p| p.x + p.y);
+    assert_eq!(grid.to_string(), "012\n123");
+
+    let lit = grid.map(|&n| n % 2 == 0);
+    assert_eq!(
+        lit.display_with(|&b| if b { '#' } else { '.' }).to_string(),
+        "#.#\n.#."
+    );
+}
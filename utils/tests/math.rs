@@ -0,0 +1,15 @@
+use aoc_utils::math::{gcd, lcm, lcm_of};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(gcd(12u64, 18), 6);
+    assert_eq!(gcd(7i32, 0), 7);
+    assert_eq!(lcm(4u8, 6), 12);
+    assert_eq!(lcm(0u32, 6), 0);
+}
+
+#[test]
+fn lcm_of_many_numbers() {
+    assert_eq!(lcm_of([23u64, 19, 13, 17]), 96577);
+    assert_eq!(lcm_of(Vec::<u64>::new()), 1);
+}
@@ -0,0 +1,32 @@
+use aoc_utils::Point;
+
+#[test]
+fn manhattan_distance_works_for_any_width() {
+    assert_eq!(
+        Point::new(-2i64, 3).manhattan_distance(Point::new(4, -1)),
+        10
+    );
+    assert_eq!(
+        Point::new(200u8, 3).manhattan_distance(Point::new(5, 50)),
+        242
+    );
+    assert_eq!(
+        Point::new(7usize, 1).manhattan_distance(Point::new(7, 1)),
+        0
+    );
+}
+
+#[test]
+fn chebyshev_distance_is_the_longest_axis() {
+    assert_eq!(Point::new(0i32, 0).chebyshev_distance(Point::new(-3, 2)), 3);
+}
+
+#[test]
+fn points_add_and_subtract() {
+    let mut p = Point::new(1i32, 2) + Point::new(3, -4);
+    assert_eq!(p, Point::new(4, -2));
+
+    p -= Point::new(4, -2);
+    assert_eq!(p, Point::default());
+    assert_eq!(Point::new(5, 7).to_string(), "(5, 7)");
+}